@@ -0,0 +1,38 @@
+//! Random location ID lists.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::fmt::Write;
+
+/// `size` lines of two 5-digit location IDs, with about a third of the right IDs also found in
+/// the left list so that similarity scores are not all zero.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut left_ids = Vec::with_capacity(size);
+    let mut input = String::new();
+    for _ in 0..size {
+        let left: u32 = rng.random_range(10_000..100_000);
+        left_ids.push(left);
+        let right = if rng.random_bool(0.3) {
+            left_ids[rng.random_range(0..left_ids.len())]
+        } else {
+            rng.random_range(10_000..100_000)
+        };
+        writeln!(input, "{}   {}", left, right).unwrap();
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exercise_1, exercise_2};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(1_000, 1);
+        assert_eq!(input, generate(1_000, 1));
+        assert_eq!(input.lines().count(), 1_000);
+        assert!(exercise_1(input.as_bytes()).unwrap() > 0);
+        assert!(exercise_2(input.as_bytes()).unwrap() > 0);
+    }
+}
@@ -0,0 +1,110 @@
+pub mod generator;
+
+use aoc_common::{Part, PuzzleError, PuzzleResult, Solution};
+use std::{collections::BTreeMap, io::BufRead};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Historian Hysteria"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<anyhow::Result<()>> {
+        Some(count_ids(input.as_bytes()).map(|_| ()).map_err(Into::into))
+    }
+
+    fn solve_reader(&self, part: Part, input: &mut dyn BufRead) -> Option<anyhow::Result<String>> {
+        let answer = match part {
+            Part::One => exercise_1(input).map(|n| n.to_string()),
+            Part::Two => exercise_2(input).map(|n| n.to_string()),
+        };
+        Some(answer.map_err(Into::into))
+    }
+
+    fn part_1(&self, input: &str) -> anyhow::Result<String> {
+        Ok(exercise_1(input.as_bytes())?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> anyhow::Result<String> {
+        Ok(exercise_2(input.as_bytes())?.to_string())
+    }
+}
+
+/// Reads the pair of location IDs on a line, `None` for a blank line.
+fn parse_line(index: usize, line: &str) -> PuzzleResult<Option<(i32, i32)>> {
+    let numbers: Vec<&str> = line.split_whitespace().collect();
+    let [num_1, num_2] = numbers[..] else {
+        if numbers.is_empty() {
+            return Ok(None);
+        }
+        let message = format!("Expected two location IDs, found {}", numbers.len());
+        return Err(match numbers.get(2) {
+            Some(extra) => PuzzleError::malformed_token(index, line, extra, message),
+            None => PuzzleError::malformed(index, line.trim_end().len(), line, message),
+        });
+    };
+    let parse = |num: &str| {
+        num.parse::<i32>()
+            .map_err(|e| PuzzleError::malformed_token(index, line, num, e))
+    };
+    Ok(Some((parse(num_1)?, parse(num_2)?)))
+}
+
+/// How many times each location ID appears in the left and right lists, which is all both
+/// parts need and takes memory in the number of distinct IDs rather than of lines.
+fn count_ids(buffered: impl BufRead) -> PuzzleResult<(BTreeMap<i32, u64>, BTreeMap<i32, u64>)> {
+    let mut left_counts = BTreeMap::new();
+    let mut right_counts = BTreeMap::new();
+
+    for (index, line) in buffered.lines().enumerate() {
+        if let Some((n_1, n_2)) = parse_line(index, &line?)? {
+            *left_counts.entry(n_1).or_insert(0) += 1;
+            *right_counts.entry(n_2).or_insert(0) += 1;
+        }
+    }
+
+    Ok((left_counts, right_counts))
+}
+
+pub fn exercise_1(buffered: impl BufRead) -> PuzzleResult<i64> {
+    let (left_counts, right_counts) = count_ids(buffered)?;
+
+    // Pairs up the sorted lists, walking both in order and taking as many equal pairs at once
+    // as both counts allow.
+    let mut left_list = left_counts.into_iter();
+    let mut right_list = right_counts.into_iter();
+    let (mut left, mut right) = (left_list.next(), right_list.next());
+    let mut total = 0;
+    while let (Some((i, i_count)), Some((j, j_count))) = (&mut left, &mut right) {
+        let pairs = (*i_count).min(*j_count);
+        total += (i64::from(*i) - i64::from(*j)).abs() * pairs as i64;
+        *i_count -= pairs;
+        *j_count -= pairs;
+        if *i_count == 0 {
+            left = left_list.next();
+        }
+        if *j_count == 0 {
+            right = right_list.next();
+        }
+    }
+
+    Ok(total)
+}
+
+pub fn exercise_2(buffered: impl BufRead) -> PuzzleResult<i64> {
+    let (left_counts, right_counts) = count_ids(buffered)?;
+
+    Ok(left_counts
+        .iter()
+        .map(|(i, &count)| i64::from(*i) * (count * right_counts.get(i).unwrap_or(&0)) as i64)
+        .sum())
+}
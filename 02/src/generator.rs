@@ -0,0 +1,48 @@
+//! Random reactor reports.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// `size` reports of 5 to 8 levels. Each starts out safe, steadily increasing or decreasing by 1
+/// to 3, and then gets zero, one or two bad levels so that all three outcomes are represented.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut input = String::new();
+    for _ in 0..size {
+        let length = rng.random_range(5..=8);
+        let ascending = rng.random_bool(0.5);
+        let mut level: i32 = if ascending {
+            rng.random_range(1..=60)
+        } else {
+            rng.random_range(40..=99)
+        };
+        let mut report = Vec::with_capacity(length);
+        for _ in 0..length {
+            report.push(level);
+            let step = rng.random_range(1..=3);
+            level += if ascending { step } else { -step };
+        }
+        for _ in 0..rng.random_range(0..=2) {
+            let i = rng.random_range(0..length);
+            report[i] = rng.random_range(1..=99);
+        }
+        let report: Vec<String> = report.iter().map(i32::to_string).collect();
+        input.push_str(&report.join(" "));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exercise_1, exercise_2};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(1_000, 2);
+        assert_eq!(input, generate(1_000, 2));
+        let safe = exercise_1(input.as_bytes()).unwrap();
+        let tolerated = exercise_2(input.as_bytes()).unwrap();
+        assert!(0 < safe && safe < tolerated && tolerated < 1_000);
+    }
+}
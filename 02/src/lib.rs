@@ -0,0 +1,108 @@
+pub mod generator;
+
+use aoc_common::{Part, PuzzleError, PuzzleResult, Solution};
+use std::io::BufRead;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Red-Nosed Reports"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<anyhow::Result<()>> {
+        let parsed = input
+            .lines()
+            .enumerate()
+            .try_for_each(|(index, line)| parse_level(index, line).map(|_| ()));
+        Some(parsed.map_err(Into::into))
+    }
+
+    fn solve_reader(&self, part: Part, input: &mut dyn BufRead) -> Option<anyhow::Result<String>> {
+        let answer = match part {
+            Part::One => exercise_1(input).map(|n| n.to_string()),
+            Part::Two => exercise_2(input).map(|n| n.to_string()),
+        };
+        Some(answer.map_err(Into::into))
+    }
+
+    fn part_1(&self, input: &str) -> anyhow::Result<String> {
+        Ok(exercise_1(input.as_bytes())?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> anyhow::Result<String> {
+        Ok(exercise_2(input.as_bytes())?.to_string())
+    }
+}
+
+fn valid_level(level: &[i32]) -> bool {
+    // If the slice has less than 2 elements, it is an automatic success.
+    if level.len() < 2 {
+        return true;
+    }
+
+    let ascending = level[0] < level[1];
+
+    level.windows(2).all(|pair| {
+        let i = pair[0];
+        let j = pair[1];
+        valid_condition(i, j, ascending)
+    })
+}
+
+fn valid_condition(i: i32, j: i32, ascending: bool) -> bool {
+    if ascending {
+        (i < j) && (j - i) <= 3
+    } else {
+        (i > j) && (i - j) <= 3
+    }
+}
+
+fn parse_level(index: usize, line: &str) -> PuzzleResult<Vec<i32>> {
+    line.split_whitespace()
+        .map(|x| {
+            x.parse::<i32>()
+                .map_err(|e| PuzzleError::malformed_token(index, line, x, e))
+        })
+        .collect()
+}
+
+pub fn exercise_1(buffered: impl BufRead) -> PuzzleResult<usize> {
+    let mut valid_levels = 0;
+    for (index, line) in buffered.lines().enumerate() {
+        if valid_level(&parse_level(index, &line?)?) {
+            valid_levels += 1;
+        }
+    }
+    Ok(valid_levels)
+}
+
+/// Whether the level is valid once at most one of its values is removed.
+fn valid_level_with_removal(level: &[i32], scratch: &mut Vec<i32>) -> bool {
+    valid_level(level)
+        || (0..level.len()).any(|skip_idx| {
+            scratch.clear();
+            scratch.extend_from_slice(&level[..skip_idx]);
+            scratch.extend_from_slice(&level[skip_idx + 1..]);
+            valid_level(scratch)
+        })
+}
+
+pub fn exercise_2(buffered: impl BufRead) -> PuzzleResult<usize> {
+    let mut scratch = Vec::new();
+    let mut valid_levels = 0;
+    for (index, line) in buffered.lines().enumerate() {
+        if valid_level_with_removal(&parse_level(index, &line?)?, &mut scratch) {
+            valid_levels += 1;
+        }
+    }
+    Ok(valid_levels)
+}
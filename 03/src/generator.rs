@@ -0,0 +1,75 @@
+//! Random corrupted memory.
+
+use rand::{rngs::StdRng, seq::IndexedRandom, Rng, SeedableRng};
+
+/// Corruption around the instructions, some of it nearly valid.
+const NOISE: &[&str] = &[
+    "what()",
+    "who()",
+    "select()",
+    "from()",
+    "#",
+    "%",
+    "&",
+    "[",
+    "]",
+    "{",
+    "}",
+    "<",
+    ">",
+    "'",
+    "!",
+    "?",
+    "@",
+    "^",
+    "~",
+    " ",
+    "mul(4*",
+    "mul[3,7]",
+    "mul ( 2 , 4 )",
+    "mul(32,64]",
+    "do(",
+    "don't[",
+    "mul(1234,5)",
+];
+
+/// `size` instructions, `mul(a,b)` with 1 to 3 digit arguments or `do()` and `don't()`, buried
+/// in corruption over lines of about 50 instructions.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut input = String::new();
+    for i in 0..size {
+        for _ in 0..rng.random_range(0..4) {
+            input.push_str(NOISE.choose(&mut rng).unwrap());
+        }
+        match rng.random_range(0..10) {
+            0 => input.push_str("do()"),
+            1 => input.push_str("don't()"),
+            _ => {
+                let a = rng.random_range(1..1000);
+                let b = rng.random_range(1..1000);
+                input.push_str(&format!("mul({},{})", a, b));
+            }
+        }
+        if i % 50 == 49 {
+            input.push('\n');
+        }
+    }
+    input.push('\n');
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exercise_1, exercise_2};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(500, 3);
+        assert_eq!(input, generate(500, 3));
+        let all = exercise_1(&input).unwrap();
+        let enabled = exercise_2(&input).unwrap();
+        assert!(0 < enabled && enabled < all);
+    }
+}
@@ -0,0 +1,110 @@
+pub mod generator;
+
+use aoc_common::{PuzzleError, PuzzleResult, Solution};
+use regex::{Captures, Regex};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Mull It Over"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<anyhow::Result<()>> {
+        Some(read_operations(input).map(|_| ()).map_err(Into::into))
+    }
+
+    fn part_1(&self, input: &str) -> anyhow::Result<String> {
+        Ok(exercise_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> anyhow::Result<String> {
+        Ok(exercise_2(input)?.to_string())
+    }
+}
+
+/// Reads the `i`-th capture group of `cap`, an integer argument of `mul`.
+fn argument(input_text: &str, cap: &Captures, i: usize) -> PuzzleResult<i32> {
+    let whole = cap.get_match();
+    let argument = cap.get(i).ok_or_else(|| {
+        PuzzleError::malformed_at(input_text, whole.start(), format!("Missing argument {}", i))
+    })?;
+    argument
+        .as_str()
+        .parse::<i32>()
+        .map_err(|e| PuzzleError::malformed_at(input_text, argument.start(), e))
+}
+
+pub fn exercise_1(input_text: &str) -> PuzzleResult<i64> {
+    let pattern = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").expect("The pattern is valid");
+
+    pattern
+        .captures_iter(input_text)
+        .map(|cap| {
+            let a = argument(input_text, &cap, 1)?;
+            let b = argument(input_text, &cap, 2)?;
+            Ok(i64::from(a * b))
+        })
+        .sum()
+}
+
+#[derive(Debug)]
+enum Operation {
+    Mul(i32, i32),
+    Do,
+    DoNot
+}
+
+/// The `mul`, `do` and `don't` instructions, in order, ignoring the corrupted characters around them.
+fn read_operations(input_text: &str) -> PuzzleResult<Vec<Operation>> {
+    let pattern =
+        Regex::new(r"(do\(\)|don't\(\)|mul\((\d{1,3}),(\d{1,3})\))").expect("The pattern is valid");
+
+    pattern
+        .captures_iter(input_text)
+        .map(|cap| -> PuzzleResult<Operation> {
+            match &cap[1] {
+                "do()" => Ok(Operation::Do),
+                "don't()" => Ok(Operation::DoNot),
+                _ => {
+                    let a = argument(input_text, &cap, 2)?;
+                    let b = argument(input_text, &cap, 3)?;
+                    Ok(Operation::Mul(a, b))
+                }
+            }
+        })
+        .collect()
+}
+
+pub fn exercise_2(input_text: &str) -> PuzzleResult<i64> {
+    let operations = read_operations(input_text)?;
+
+    let mut sum = 0;
+    let mut do_operation = true;
+
+    for op in operations {
+        match op {
+            Operation::Mul(a, b) => {
+                if do_operation {
+                    sum += i64::from(a * b);
+                }
+            },
+            Operation::Do => {
+                do_operation = true;
+            },
+            Operation::DoNot => {
+                do_operation = false;
+            }
+        }
+    }
+
+    Ok(sum)
+}
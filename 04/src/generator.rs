@@ -0,0 +1,79 @@
+//! Random word searches.
+
+use rand::{rngs::StdRng, seq::IndexedRandom, Rng, SeedableRng};
+
+const DIRECTIONS: [(isize, isize); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+/// A square word search of side `size` made of the letters of `XMAS`, with about `size` more
+/// `XMAS` words and `size / 2` more `X-MAS` crosses written over them in random places.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut rows: Vec<Vec<char>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| *b"XMAS".choose(&mut rng).unwrap() as char)
+                .collect()
+        })
+        .collect();
+
+    for _ in 0..size {
+        let (dx, dy) = *DIRECTIONS.choose(&mut rng).unwrap();
+        let (x, y) = (rng.random_range(0..size), rng.random_range(0..size));
+        let cells: Option<Vec<(usize, usize)>> = (0..4)
+            .map(|i| Some((x.checked_add_signed(dx * i)?, y.checked_add_signed(dy * i)?)))
+            .map(|cell| cell.filter(|&(x, y)| x < size && y < size))
+            .collect();
+        for ((x, y), letter) in cells.into_iter().flatten().zip("XMAS".chars()) {
+            rows[y][x] = letter;
+        }
+    }
+
+    if size >= 3 {
+        for _ in 0..size / 2 {
+            let (x, y) = (rng.random_range(1..size - 1), rng.random_range(1..size - 1));
+            let (m, s) = if rng.random_bool(0.5) {
+                ('M', 'S')
+            } else {
+                ('S', 'M')
+            };
+            let (m2, s2) = if rng.random_bool(0.5) {
+                ('M', 'S')
+            } else {
+                ('S', 'M')
+            };
+            rows[y][x] = 'A';
+            rows[y - 1][x - 1] = m;
+            rows[y + 1][x + 1] = s;
+            rows[y - 1][x + 1] = m2;
+            rows[y + 1][x - 1] = s2;
+        }
+    }
+
+    rows.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exercise_1, exercise_2};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(40, 4);
+        assert_eq!(input, generate(40, 4));
+        assert_eq!(input.lines().count(), 40);
+        assert!(exercise_1(&input).unwrap() >= 20);
+        assert!(exercise_2(&input).unwrap() >= 10);
+    }
+}
@@ -0,0 +1,57 @@
+//! Random page ordering rules and print queues.
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::fmt::Write;
+
+/// Pages numbered with two digits, as in the puzzle.
+const PAGES: usize = 49;
+
+/// Rules ordering every pair of `49` distinct two-digit pages, followed by `size` print queues of
+/// an odd number of pages, about half of them in order.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut pages: Vec<usize> = (10..100).collect();
+    pages.shuffle(&mut rng);
+    pages.truncate(PAGES);
+
+    // The shuffled order is the order every rule agrees with.
+    let mut input = String::new();
+    let mut rules = Vec::with_capacity(PAGES * (PAGES - 1) / 2);
+    for (i, before) in pages.iter().enumerate() {
+        for after in &pages[i + 1..] {
+            rules.push((before, after));
+        }
+    }
+    rules.shuffle(&mut rng);
+    for (before, after) in rules {
+        writeln!(input, "{}|{}", before, after).unwrap();
+    }
+    input.push('\n');
+
+    for _ in 0..size {
+        let length = 2 * rng.random_range(2..12) + 1;
+        let mut positions: Vec<usize> = (0..PAGES).collect();
+        positions.shuffle(&mut rng);
+        positions.truncate(length);
+        if rng.random_bool(0.5) {
+            positions.sort();
+        }
+        let queue: Vec<String> = positions.iter().map(|&i| pages[i].to_string()).collect();
+        writeln!(input, "{}", queue.join(",")).unwrap();
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exercise_1, exercise_2};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(200, 5);
+        assert_eq!(input, generate(200, 5));
+        assert!(exercise_1(input.as_bytes()).unwrap() > 0);
+        assert!(exercise_2(input.as_bytes()).unwrap() > 0);
+    }
+}
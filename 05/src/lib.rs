@@ -0,0 +1,284 @@
+pub mod generator;
+
+use anyhow::Result;
+use aoc_common::{Part, PuzzleError, PuzzleResult, Solution};
+use std::{
+    collections::HashSet,
+    io::{self, BufRead},
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Print Queue"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(for_each_queue(input.as_bytes(), |_, _| Ok(())).map_err(Into::into))
+    }
+
+    fn solve_reader(&self, part: Part, input: &mut dyn BufRead) -> Option<Result<String>> {
+        let answer = match part {
+            Part::One => exercise_1(input).map(|n| n.to_string()),
+            Part::Two => exercise_2(input).map(|n| n.to_string()),
+        };
+        Some(answer.map_err(Into::into))
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(exercise_1(input.as_bytes())?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(exercise_2(input.as_bytes())?.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Rule {
+    before: usize,
+    after: usize,
+}
+
+/// Read the rules, up to the blank line separating them from the print queues.
+///
+/// The rules are pairs of numbers, separated by a pipe. Example:
+/// ```text
+/// 15|78
+/// 65|46
+/// 65|23
+/// ```
+fn read_rules(
+    lines: &mut impl Iterator<Item = (usize, io::Result<String>)>,
+) -> PuzzleResult<HashSet<Rule>> {
+    let mut rules = HashSet::new();
+    let mut last_line = (0, String::new());
+    for (index, line) in lines {
+        let line = line?;
+        if line.trim().is_empty() {
+            return Ok(rules);
+        }
+        let (before, after) = line.split_once("|").ok_or_else(|| {
+            let offset = line
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(line.len());
+            PuzzleError::malformed(
+                index,
+                offset,
+                &line,
+                "Rule must contain two numbers separated by a pipe.",
+            )
+        })?;
+        rules.insert(Rule {
+            before: parse_page(index, &line, before)?,
+            after: parse_page(index, &line, after)?,
+        });
+        last_line = (index, line);
+    }
+    let (index, line) = last_line;
+    Err(PuzzleError::malformed(
+        index,
+        line.len(),
+        &line,
+        "Input must contain two sections separated by double newline.",
+    ))
+}
+
+/// Read the rules, then call `f` on each print queue as soon as it is read, so that only one
+/// print queue is held in memory at a time.
+///
+/// Each print queue is a list of numbers separated by commas. Example:
+/// ```text
+/// 38,68,88,11,13,64,29,37,92,72,26,83,89
+/// ```
+fn for_each_queue<F>(buffered: impl BufRead, mut f: F) -> PuzzleResult<()>
+where
+    F: FnMut(&[usize], &HashSet<Rule>) -> PuzzleResult<()>,
+{
+    let mut lines = buffered.lines().enumerate();
+    let rules = read_rules(&mut lines)?;
+    for (index, line) in lines {
+        let line = line?;
+        let print_queue = line
+            .split(",")
+            .map(|x| parse_page(index, &line, x))
+            .collect::<PuzzleResult<Vec<usize>>>()?;
+        f(&print_queue, &rules)?;
+    }
+    Ok(())
+}
+
+fn parse_page(index: usize, line: &str, page: &str) -> PuzzleResult<usize> {
+    page.parse()
+        .map_err(|e| PuzzleError::malformed_token(index, line, page, e))
+}
+
+fn is_valid(print_queue: &[usize], rules: &HashSet<Rule>) -> bool {
+    print_queue.windows(2).all(|w| {
+        let before = w[0];
+        let after = w[1];
+        rules.contains(&Rule { before, after })
+    })
+}
+
+/// The goal of this exercise is to find the sum of the middle value of each valid line.
+pub fn exercise_1(buffered: impl BufRead) -> PuzzleResult<usize> {
+    let mut sum = 0;
+    for_each_queue(buffered, |print_queue, rules| {
+        if is_valid(print_queue, rules) {
+            sum += print_queue[print_queue.len() / 2];
+        }
+        Ok(())
+    })?;
+    Ok(sum)
+}
+
+/// This function takes an invalid line and a set of rules and returns a corrected line.
+///
+/// Fails when no rule orders two pages that are out of place, which would otherwise swap them back
+/// and forth forever. Every other swap moves a page towards the start past one it must follow, so
+/// the sort ends even when the rules go round in a cycle.
+fn transform_invalid_line(
+    invalid_line: &[usize],
+    rules: &HashSet<Rule>,
+) -> PuzzleResult<Vec<usize>> {
+    let mut buffer = invalid_line.to_vec();
+
+    let mut k: usize = 0;
+    while k + 1 < buffer.len() {
+        let before = buffer[k];
+        let after = buffer[k + 1];
+
+        // If the pair is in the set, this is not the violating element and we can continue the search.
+        if rules.contains(&Rule { before, after }) {
+            k += 1;
+            continue;
+        }
+
+        // At this point we have a violation.
+        if !rules.contains(&Rule {
+            before: after,
+            after: before,
+        }) {
+            return Err(PuzzleError::unsolvable(format!(
+                "No rule orders pages {} and {}",
+                before, after
+            )));
+        }
+
+        // Swap the elements.
+        buffer[k] = after;
+        buffer[k + 1] = before;
+
+        // We have to go back and check the previous pair (unless we are at the start).
+        k = k.saturating_sub(1);
+    }
+    Ok(buffer)
+}
+
+/// The goal of this exercise is to find the sum of the middle value of each corrected line.
+pub fn exercise_2(buffered: impl BufRead) -> PuzzleResult<usize> {
+    let mut sum = 0;
+    for_each_queue(buffered, |print_queue, rules| {
+        if !is_valid(print_queue, rules) {
+            let corrected_line = transform_invalid_line(print_queue, rules)?;
+            sum += corrected_line[corrected_line.len() / 2];
+        }
+        Ok(())
+    })?;
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Tries every ordering of the pages, keeping the one where every pair follows the rules.
+    fn brute_force_correct(pages: &[usize], rules: &HashSet<Rule>) -> Option<Vec<usize>> {
+        fn permute(
+            ordered: &mut Vec<usize>,
+            left: &mut Vec<usize>,
+            rules: &HashSet<Rule>,
+        ) -> Option<Vec<usize>> {
+            if left.is_empty() {
+                let follows_rules = ordered.iter().enumerate().all(|(i, &before)| {
+                    ordered[i + 1..]
+                        .iter()
+                        .all(|&after| rules.contains(&Rule { before, after }))
+                });
+                return follows_rules.then(|| ordered.clone());
+            }
+            for i in 0..left.len() {
+                ordered.push(left.remove(i));
+                let found = permute(ordered, left, rules);
+                left.insert(i, ordered.pop().unwrap());
+                if found.is_some() {
+                    return found;
+                }
+            }
+            None
+        }
+        permute(&mut vec![], &mut pages.to_vec(), rules)
+    }
+
+    /// Rules ordering every pair of a few pages, as in the puzzle, and a queue of distinct pages.
+    fn rules_and_queue() -> impl Strategy<Value = (HashSet<Rule>, Vec<usize>)> {
+        let pages: Vec<usize> = (10..40).collect();
+        (
+            Just(pages.clone()).prop_shuffle(),
+            prop::sample::subsequence(pages, 0..8).prop_shuffle(),
+        )
+            .prop_map(|(order, queue)| {
+                let rules = order
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, &before)| {
+                        order[i + 1..]
+                            .iter()
+                            .map(move |&after| Rule { before, after })
+                    })
+                    .collect();
+                (rules, queue)
+            })
+    }
+
+    fn rules(pairs: &[(usize, usize)]) -> HashSet<Rule> {
+        pairs
+            .iter()
+            .map(|&(before, after)| Rule { before, after })
+            .collect()
+    }
+
+    #[test]
+    fn test_transform_invalid_line_without_order() {
+        assert_eq!(
+            transform_invalid_line(&[1, 2, 3], &rules(&[(1, 2)]))
+                .unwrap_err()
+                .to_string(),
+            "Unsolvable input: No rule orders pages 2 and 3"
+        );
+        assert_eq!(
+            transform_invalid_line(&[1, 2, 3], &rules(&[(2, 1), (3, 2), (1, 3)])).unwrap(),
+            [2, 1, 3]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_transform_invalid_line_matches_brute_force((rules, queue) in rules_and_queue()) {
+            let corrected = transform_invalid_line(&queue, &rules).unwrap();
+            prop_assert_eq!(Some(corrected.clone()), brute_force_correct(&queue, &rules));
+            prop_assert!(is_valid(&corrected, &rules));
+        }
+    }
+}
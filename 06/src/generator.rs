@@ -0,0 +1,58 @@
+//! Random lab maps.
+
+use grid::{Direction, Grid, Point};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::HashSet;
+
+/// A square map of side `size` (at least 1) with about one obstacle in ten cells and the guard
+/// facing up somewhere, from where the guard walks out of the map. Maps where the guard would walk
+/// in circles are thrown away.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+    loop {
+        let mut map = Grid::new(size, size, '.');
+        for position in map.positions().collect::<Vec<_>>() {
+            if rng.random_bool(0.1) {
+                map[position] = '#';
+            }
+        }
+        let guard = (rng.random_range(0..size), rng.random_range(0..size));
+        map[guard] = '^';
+        if exits(&map, guard.into()) {
+            return map.to_string();
+        }
+    }
+}
+
+fn exits(map: &Grid<char>, mut position: Point) -> bool {
+    let mut direction = Direction::North;
+    let mut visited = HashSet::new();
+    while visited.insert((position, direction)) {
+        let Some(next) = map.translate(position, direction.vector()) else {
+            return true;
+        };
+        if map[next] == '#' {
+            direction = direction.turn_right();
+        } else {
+            position = next;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(30, 6);
+        assert_eq!(input, generate(30, 6));
+        assert_eq!(input.matches('^').count(), 1);
+        assert!(Day06.part_1(&input).unwrap().parse::<usize>().unwrap() > 0);
+        Day06.part_2(&input).unwrap();
+    }
+}
@@ -0,0 +1,208 @@
+pub mod generator;
+
+use anyhow::{bail, Result};
+use aoc_common::{PuzzleError, Solution};
+use grid::{Direction, Grid, Point};
+use std::{collections::HashSet, fmt::Display};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Guard Gallivant"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(preprocessing(input).map(|_| ()))
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        let mut maze = preprocessing(input)?;
+        Ok(exercise_1(&mut maze).to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        let mut maze = preprocessing(input)?;
+        Ok(exercise_2(&mut maze).to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Guard {
+    position: Point,
+    orientation: Direction,
+}
+
+impl Display for Guard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.orientation)
+    }
+}
+
+#[derive(Clone, PartialEq)]
+enum MazeCell {
+    Obstacle,
+    Empty,
+}
+
+impl Display for MazeCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MazeCell::Obstacle => write!(f, "#"),
+            MazeCell::Empty => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Maze {
+    cells: Grid<MazeCell>,
+    guard: Guard,
+    visited_locations: HashSet<Point>,
+}
+
+impl Display for Maze {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.cells.rows().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                if Point::new(j, i) == self.guard.position {
+                    write!(f, "{}", self.guard)?;
+                } else {
+                    write!(f, "{}", cell)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn preprocessing(input_string: &str) -> Result<Maze> {
+    let mut visited_locations = HashSet::new();
+
+    let mut guard = None;
+
+    let cells = Grid::parse_with(input_string, |position, c| {
+        match c {
+            '#' => return Ok(MazeCell::Obstacle),
+            '.' => return Ok(MazeCell::Empty),
+            _ => {}
+        }
+        let Some(orientation) = Direction::from_arrow(c) else {
+            bail!("Unknown cell {:?}", c);
+        };
+        guard = Some(Guard {
+            position: position.into(),
+            orientation,
+        });
+        Ok(MazeCell::Empty)
+    })?;
+    let guard = guard.ok_or_else(|| PuzzleError::unsolvable("The map has no guard"))?;
+    visited_locations.insert(guard.position);
+
+    Ok(Maze {
+        cells,
+        guard,
+        visited_locations,
+    })
+}
+
+#[derive(Debug, Clone)]
+enum GuardMove {
+    TurnRight,
+    MoveForward, // (usize, usize),
+    ExitMaze,
+}
+
+impl Maze {
+    fn move_guard(&mut self) -> GuardMove {
+        let Some(next) = self
+            .cells
+            .translate(self.guard.position, self.guard.orientation.vector())
+        else {
+            return GuardMove::ExitMaze;
+        };
+        match self.cells[next] {
+            MazeCell::Obstacle => {
+                self.guard.orientation = self.guard.orientation.turn_right();
+                GuardMove::TurnRight
+            }
+            MazeCell::Empty => {
+                self.guard.position = next;
+                self.visited_locations.insert(next);
+                GuardMove::MoveForward
+            }
+        }
+    }
+}
+
+fn exercise_1(maze: &mut Maze) -> usize {
+    while let GuardMove::TurnRight | GuardMove::MoveForward = maze.move_guard() {}
+    maze.visited_locations.len()
+}
+
+fn restore_maze(maze: &mut Maze, starting_pos: (Point, Direction), old_obstacle: Point) {
+    maze.guard.position = starting_pos.0;
+    maze.guard.orientation = starting_pos.1;
+    maze.cells[old_obstacle] = MazeCell::Empty;
+}
+
+fn break_condition(
+    maze: &Maze,
+    visited_locations_and_orientations: &HashSet<(Point, Direction)>,
+) -> bool {
+    visited_locations_and_orientations.contains(&(maze.guard.position, maze.guard.orientation))
+}
+
+fn exercise_2(maze: &mut Maze) -> usize {
+    let starting_pos = (maze.guard.position, maze.guard.orientation);
+    let mut positions_with_no_exit = 0;
+
+    // Place a new obstacle in each cell of the maze and try to exit the maze.
+    for x in 0..maze.cells.width() {
+        for y in 0..maze.cells.height() {
+            // Cannot place an obstacle in the guard starting location.
+            let obstacle = Point::new(x, y);
+            if obstacle == starting_pos.0 {
+                continue;
+            }
+            // Cannot place an obstacle if there is already an obstacle.
+            if maze.cells[obstacle] == MazeCell::Obstacle {
+                continue;
+            }
+            // Place the obstacle in the proposed location.
+            maze.cells[obstacle] = MazeCell::Obstacle;
+
+            // Initialize a new set of locations and orientations.
+            let mut visited_locations_and_orientations: HashSet<(Point, Direction)> =
+                HashSet::new();
+            // Add the guard's starting position to the set.
+            visited_locations_and_orientations.insert(starting_pos);
+
+            // Try to exit the maze.
+            while !matches!(maze.move_guard(), GuardMove::ExitMaze) {
+                // If the guard is in a position and orientation that has already been visited, this a loop with no exit.
+                if break_condition(maze, &visited_locations_and_orientations) {
+                    positions_with_no_exit += 1;
+                    break;
+                }
+
+                // Otherwise, insert to the set and keep moving.
+                visited_locations_and_orientations
+                    .insert((maze.guard.position, maze.guard.orientation));
+            }
+            // Restore the maze to its original state by removing the obstacle and resetting the guard's position and orientation.
+            restore_maze(maze, starting_pos, obstacle);
+        }
+    }
+
+    positions_with_no_exit
+}
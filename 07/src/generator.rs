@@ -0,0 +1,62 @@
+//! Random calibration equations.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::fmt::Write;
+
+/// Results are kept below this so that summing millions of them cannot overflow.
+const MAX_RESULT: usize = 1_000_000_000_000;
+
+/// `size` equations of 2 to 8 operands. A third of them can be solved with `+` and `*`, a third
+/// needs `||` as well, and the rest have a random result that usually cannot be reached.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut input = String::new();
+    let mut equations = 0;
+    while equations < size {
+        let operands: Vec<usize> = (0..rng.random_range(2..=8))
+            .map(|_| match rng.random_range(0..4) {
+                0 => rng.random_range(100..1000),
+                _ => rng.random_range(1..100),
+            })
+            .collect();
+        let operators = match rng.random_range(0..3) {
+            0 => 2,
+            1 => 3,
+            _ => 0,
+        };
+        let result = if operators == 0 {
+            Some(rng.random_range(1..MAX_RESULT))
+        } else {
+            operands[1..].iter().try_fold(operands[0], |acc, &x| {
+                match rng.random_range(0..operators) {
+                    0 => acc.checked_add(x),
+                    1 => acc.checked_mul(x),
+                    _ => format!("{}{}", acc, x).parse().ok(),
+                }
+            })
+        };
+        let Some(result) = result.filter(|&result| result < MAX_RESULT) else {
+            continue;
+        };
+        let operands: Vec<String> = operands.iter().map(usize::to_string).collect();
+        writeln!(input, "{}: {}", result, operands.join(" ")).unwrap();
+        equations += 1;
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exercise_1, exercise_2};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(300, 7);
+        assert_eq!(input, generate(300, 7));
+        assert_eq!(input.lines().count(), 300);
+        let with_two = exercise_1(input.as_bytes()).unwrap();
+        let with_three = exercise_2(input.as_bytes()).unwrap();
+        assert!(0 < with_two && with_two < with_three);
+    }
+}
@@ -0,0 +1,424 @@
+pub mod generator;
+
+use anyhow::Result;
+use aoc_common::{Part, PuzzleError, PuzzleResult, Solution};
+use std::io::BufRead;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "Bridge Repair"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(
+            sum_possible(input.as_bytes(), |_, _| false)
+                .map(|_| ())
+                .map_err(Into::into),
+        )
+    }
+
+    fn solve_reader(&self, part: Part, input: &mut dyn BufRead) -> Option<Result<String>> {
+        let answer = match part {
+            Part::One => exercise_1(input).map(|n| n.to_string()),
+            Part::Two => exercise_2(input).map(|n| n.to_string()),
+        };
+        Some(answer.map_err(Into::into))
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(exercise_1(input.as_bytes())?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(exercise_2(input.as_bytes())?.to_string())
+    }
+}
+
+// #[derive(Debug, Clone, PartialEq)]
+// enum Operation {
+//     Add,
+//     Mult,
+// }
+
+/// Smallest and largest results reachable with additions and multiplications, `None` with fewer
+/// than two operands. Saturates instead of overflowing.
+fn calculate_bounds(operands: &[usize]) -> Option<(usize, usize)> {
+    if operands.len() < 2 {
+        return None;
+    }
+    // The operands slice is a sequence of positive integers.
+
+    // The lower bound will usually be the sum of the operands, except when an operand is 1, in which case the smallest operation is to multiply by 1.
+    // There is an edge case when the inital element is 1.
+    let lower_bound = operands[1..].iter().fold(operands[0], |acc, &x| {
+        if acc == 1 {
+            x
+        } else if x == 1 {
+            acc
+        } else {
+            acc.saturating_add(x)
+        }
+    });
+    let upper_bound = operands[1..].iter().fold(operands[0], |acc, &x| {
+        if acc == 1 || x == 1{
+            acc.saturating_add(x)
+        } else {
+            acc.saturating_mul(x)
+        }
+    });
+    Some((lower_bound, upper_bound))
+}
+
+/// Whether `result` can be reached from `operands`, whose results lie within `lower_bound` and
+/// `upper_bound`, both reachable.
+fn is_possible(
+    result: usize,
+    lower_bound: usize,
+    upper_bound: usize,
+    operands: &[usize],
+) -> bool {
+    // If the result is outside the bounds, it is impossible.
+    if result < lower_bound || result > upper_bound {
+        return false;
+    }
+
+    if result == lower_bound || result == upper_bound {
+        return true;
+    }
+
+    let n = operands.len();
+
+    // If there are only two operands and the result is not equal to either of them, it is impossible.
+    if n <= 2 {
+        return false;
+    }
+
+    // Check recursively, undoing the last operation.
+    // The bounds of the head cannot be derived from the current ones: with ones among the operands,
+    // the extreme results do not always use the same operators.
+    let tail = operands[n - 1];
+    let head = &operands[..n - 1];
+    let Some((lower_bound, upper_bound)) = calculate_bounds(head) else {
+        return false;
+    };
+
+    // (Last Operation is Multiplication) || (Last Operation is Addition)
+    (result.is_multiple_of(tail) && is_possible(result / tail, lower_bound, upper_bound, head))
+        || (result > tail && is_possible(result - tail, lower_bound, upper_bound, head))
+}
+
+/// `None` on overflow.
+fn concat_integers(lhs: usize, rhs: usize) -> Option<usize> {
+    let mut n = rhs;
+    let mut lhs = lhs;
+    while n > 0 {
+        lhs = lhs.checked_mul(10)?;
+        n /= 10;
+    }
+    lhs.checked_add(rhs)
+}
+
+
+fn can_unconcat(lhs: usize, rhs: usize) -> bool {
+    let n = usize::ilog10(rhs) + 1;
+    10usize
+        .checked_pow(n)
+        .is_some_and(|power| lhs % power == rhs)
+}
+
+fn unconcat(lhs: usize, rhs: usize) -> usize {
+    let n = usize::ilog10(rhs) + 1;
+    (lhs - rhs) / 10usize.pow(n)
+}
+
+
+fn is_possible_expanded(
+    result: usize,
+    operands: &[usize],
+) -> bool {
+    let n = operands.len();
+    
+    if n == 2 {
+        let s = operands[0].checked_add(operands[1]);
+        let m = operands[0].checked_mul(operands[1]);
+        let c = concat_integers(operands[0], operands[1]);
+
+        return [s, m, c].contains(&Some(result));
+    }
+
+    let tail = operands[n - 1];
+
+    (result > tail && is_possible_expanded(result - tail, &operands[..n - 1]))
+        || (result.is_multiple_of(tail) && is_possible_expanded(result / tail, &operands[..n - 1]))
+        || (can_unconcat(result, tail) && is_possible_expanded(unconcat(result, tail), &operands[..n - 1]))
+}
+
+/// Reads a `result: operand operand...` line. There must be at least two operands, all positive.
+fn parse_calibration(index: usize, line: &str) -> PuzzleResult<(usize, Vec<usize>)> {
+    let (result, operands) = line.split_once(":").ok_or_else(|| {
+        let offset = line
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(line.len());
+        PuzzleError::malformed(index, offset, line, "Missing `:` after the result")
+    })?;
+    let result = result
+        .trim()
+        .parse::<usize>()
+        .map_err(|e| PuzzleError::malformed_token(index, line, result.trim(), e))?;
+    let operands: Vec<usize> = operands
+        .split_whitespace()
+        .map(|operand| -> PuzzleResult<usize> {
+            match operand.parse::<usize>() {
+                Ok(0) => Err(PuzzleError::malformed_token(
+                    index,
+                    line,
+                    operand,
+                    "Operands must be positive",
+                )),
+                Ok(operand) => Ok(operand),
+                Err(e) => Err(PuzzleError::malformed_token(index, line, operand, e)),
+            }
+        })
+        .collect::<PuzzleResult<_>>()?;
+    if operands.len() < 2 {
+        return Err(PuzzleError::malformed(
+            index,
+            line.len(),
+            line,
+            "Must contain at least two operands.",
+        ));
+    }
+    Ok((result, operands))
+}
+
+/// Sums the results of the calibrations that `possible` accepts, reading them one line at a time.
+fn sum_possible<F>(buffered: impl BufRead, possible: F) -> PuzzleResult<usize>
+where
+    F: Fn(usize, &[usize]) -> bool,
+{
+    let mut sum = 0;
+    for (index, line) in buffered.lines().enumerate() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+        let (result, operands) = parse_calibration(index, &line)?;
+        if possible(result, &operands[..]) {
+            sum += result;
+        }
+    }
+    Ok(sum)
+}
+
+pub fn exercise_1(buffered: impl BufRead) -> PuzzleResult<usize> {
+    sum_possible(buffered, |result, operands| {
+        // Parsing guarantees at least two operands.
+        calculate_bounds(operands).is_some_and(|(lower_bound, upper_bound)| {
+            is_possible(result, lower_bound, upper_bound, operands)
+        })
+    })
+}
+
+pub fn exercise_2(buffered: impl BufRead) -> PuzzleResult<usize> {
+    sum_possible(buffered, is_possible_expanded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use rstest::*;
+
+    /// Tries every combination of operators, left to right.
+    fn brute_force_is_possible(result: usize, operands: &[usize], concatenation: bool) -> bool {
+        fn reaches(result: usize, acc: usize, rest: &[usize], concatenation: bool) -> bool {
+            let Some((&x, rest)) = rest.split_first() else {
+                return acc == result;
+            };
+            let concatenated = concatenation.then(|| concat_integers(acc, x)).flatten();
+            [acc.checked_add(x), acc.checked_mul(x), concatenated]
+                .into_iter()
+                .flatten()
+                .any(|acc| reaches(result, acc, rest, concatenation))
+        }
+        reaches(result, operands[0], &operands[1..], concatenation)
+    }
+
+    /// Operands with plenty of ones, and a result that is reachable half of the time.
+    fn calibration() -> impl Strategy<Value = (usize, Vec<usize>)> {
+        prop::collection::vec(prop_oneof![Just(1usize), 1..20usize, 1..1000usize], 2..8)
+            .prop_flat_map(|operands| {
+                let n = operands.len();
+                (
+                    Just(operands),
+                    prop::collection::vec(0..3u8, n - 1),
+                    any::<bool>(),
+                    1..100_000usize,
+                )
+            })
+            .prop_map(|(operands, operators, reachable, random)| {
+                let result = if reachable {
+                    operands[1..]
+                        .iter()
+                        .zip(&operators)
+                        .fold(operands[0], |acc, (&x, op)| match op {
+                            0 => acc.saturating_add(x),
+                            1 => acc.saturating_mul(x),
+                            _ => concat_integers(acc, x).unwrap_or(usize::MAX),
+                        })
+                } else {
+                    random
+                };
+                (result, operands)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_is_possible_matches_brute_force((result, operands) in calibration()) {
+            let (lower_bound, upper_bound) = calculate_bounds(&operands).unwrap();
+            prop_assert_eq!(
+                is_possible(result, lower_bound, upper_bound, &operands),
+                brute_force_is_possible(result, &operands, false)
+            );
+        }
+
+        #[test]
+        fn prop_is_possible_expanded_matches_brute_force((result, operands) in calibration()) {
+            prop_assert_eq!(
+                is_possible_expanded(result, &operands),
+                brute_force_is_possible(result, &operands, true)
+            );
+        }
+    }
+
+    #[rstest]
+    #[case(12345, 2345, true)]
+    #[case(1237089, 7089, true)]
+    #[case(12345, 234, false)]
+    #[case(1, 1, true)]
+    #[case(123, 123, true)]
+    fn test_can_unconcat(#[case] lhs: usize, #[case] rhs: usize, #[case] expected: bool) {
+        assert_eq!(can_unconcat(lhs, rhs), expected);
+    }
+
+    #[rstest]
+    #[case(12345, 2345, 1)]
+    #[case(1237089, 7089, 123)]
+    #[case(123, 123, 0)]
+    fn test_unconcat(#[case] lhs: usize, #[case] rhs: usize, #[case] expected: usize) {
+        assert_eq!(unconcat(lhs, rhs), expected);
+    }
+
+    #[rstest]
+    #[case(&[2, 1, 3, 4, 1], 9, 37)]
+    #[case(&[2, 1, 1, 3], 5, 12)]
+    #[case(&[1, 1, 1], 1, 3)]
+    #[case(&[1, 2, 3], 5, 9)]
+    #[case(&[10, 19], 29, 190)]
+    fn test_calculate_bounds(
+        #[case] operands: &[usize],
+        #[case] lower: usize,
+        #[case] upper: usize,
+    ) {
+        let (lower_bound, upper_bound) = calculate_bounds(operands).unwrap();
+        assert_eq!(lower_bound, lower);
+        assert_eq!(upper_bound, upper);
+    }
+
+    #[rstest]
+    #[case(
+        "190 10 19",
+        "Line 1, column 4: Missing `:` after the result\n1 | 190 10 19\n  |    ^"
+    )]
+    #[case(
+        "190: 10",
+        "Line 1, column 8: Must contain at least two operands.\n1 | 190: 10\n  |        ^"
+    )]
+    #[case(
+        "190: 10 0",
+        "Line 1, column 9: Operands must be positive\n1 | 190: 10 0\n  |         ^"
+    )]
+    #[case(
+        "19x: 10 19",
+        "Line 1, column 1: invalid digit found in string\n1 | 19x: 10 19\n  | ^^^"
+    )]
+    fn test_parse_calibration_errors(#[case] line: &str, #[case] expected: &str) {
+        assert_eq!(
+            parse_calibration(0, line).unwrap_err().to_string(),
+            expected
+        );
+        assert_eq!(calculate_bounds(&[7]), None);
+    }
+
+    #[rstest]
+    #[case(190, &[10, 19], true)]
+    #[case(3267, &[81, 40, 27], true)]
+    #[case(83, &[17, 15], false)]
+    #[case(156, &[15, 6], false)]
+    #[case(7290, &[6, 8, 6, 15], false)]
+    #[case(161011, &[16, 10, 13], false)]
+    #[case(192, &[17, 8, 14], false)]
+    #[case(21037, &[9, 7, 18, 13], false)]
+    #[case(292, &[11, 6, 16, 20], true)]
+    fn test_is_possible(#[case] result: usize, #[case] operands: &[usize], #[case] expected: bool) {
+        let lower_bound: usize = operands.iter().sum();
+        let upper_bound: usize = operands.iter().product();
+        assert_eq!(
+            is_possible(result, lower_bound, upper_bound, operands),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(3, &[1, 1, 2], true)]
+    #[case(4, &[1, 1, 2], true)]
+    #[case(5, &[2, 1, 1, 3], true)]
+    #[case(8, &[2, 1, 1, 3], false)]
+    fn test_is_possible_with_ones(
+        #[case] result: usize,
+        #[case] operands: &[usize],
+        #[case] expected: bool,
+    ) {
+        let (lower_bound, upper_bound) = calculate_bounds(operands).unwrap();
+        assert_eq!(
+            is_possible(result, lower_bound, upper_bound, operands),
+            expected
+        );
+    }
+
+    #[fixture]
+    fn sample_input_string() -> &'static str {
+        "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20"
+    }
+
+    #[rstest]
+    fn test_exercise_1(#[from(sample_input_string)] input: &str) {
+        let result = exercise_1(input.as_bytes());
+        assert!(result.is_ok_and(|x| x == 3749));
+    }
+
+    #[rstest]
+    fn test_exercise_2_reads_incrementally(#[from(sample_input_string)] input: &str) {
+        let reader = std::io::BufReader::with_capacity(4, input.as_bytes());
+        assert_eq!(exercise_2(reader).unwrap(), 11387);
+    }
+}
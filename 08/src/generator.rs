@@ -0,0 +1,44 @@
+//! Random antenna maps.
+
+use grid::Grid;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A square map of side `size` (at least 3) with `size / 2` frequencies, between 2 and 62, of 2
+/// to 4 antennas each.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(3);
+    let mut map = Grid::new(size, size, '.');
+
+    let mut free: Vec<(usize, usize)> = map.positions().collect();
+    free.shuffle(&mut rng);
+    for &frequency in &FREQUENCIES[..(size / 2).clamp(2, FREQUENCIES.len())] {
+        for _ in 0..rng.random_range(2..=4) {
+            // Leaves some room for the antinodes.
+            if free.len() <= size * size / 2 {
+                break;
+            }
+            map[free.pop().unwrap()] = frequency as char;
+        }
+    }
+    map.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day08;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(40, 8);
+        assert_eq!(input, generate(40, 8));
+        let antinodes: usize = Day08.part_1(&input).unwrap().parse().unwrap();
+        let resonant: usize = Day08.part_2(&input).unwrap().parse().unwrap();
+        assert!(0 < antinodes && antinodes < resonant);
+        assert!(Day08.part_1(&generate(0, 8)).is_ok());
+    }
+}
@@ -0,0 +1,262 @@
+pub mod generator;
+
+use anyhow::{ensure, Result};
+use aoc_common::{PuzzleError, Solution};
+use grid::{Grid, Point, Vector};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Resonant Collinearity"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(AntennaGrid::from_str(input).map(|_| ()))
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        let antenna_grid = AntennaGrid::from_str(input)?;
+        let antennas = preprocessing(&antenna_grid)?;
+        let antenna_pairs = build_pairs(&antennas)?;
+        let bounds = (antenna_grid.cells.height(), antenna_grid.cells.width());
+        Ok(exercise_1(&antenna_pairs, bounds)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        let antenna_grid = AntennaGrid::from_str(input)?;
+        let antennas = preprocessing(&antenna_grid)?;
+        let antenna_pairs = build_pairs(&antennas)?;
+        let bounds = (antenna_grid.cells.height(), antenna_grid.cells.width());
+        Ok(exercise_2(&antenna_pairs, bounds)?.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct AntennaCell {
+    antenna: Option<char>,
+    coords: (usize, usize),
+}
+
+#[derive(Debug)]
+struct AntennaGrid {
+    cells: Grid<AntennaCell>,
+}
+
+impl AntennaGrid {
+    fn from_str(input_str: &str) -> Result<Self> {
+        let cells = Grid::parse_with(input_str, |coords, c| {
+            Ok(AntennaCell {
+                antenna: (c != '.').then_some(c),
+                coords,
+            })
+        })?;
+        if cells.height() != cells.width() {
+            return Err(PuzzleError::dimension_mismatch(
+                "Number of rows of the square grid",
+                cells.width(),
+                cells.height(),
+            )
+            .into());
+        }
+
+        Ok(Self { cells })
+    }
+}
+
+fn preprocessing(antenna_grid: &AntennaGrid) -> Result<HashMap<char, Vec<&AntennaCell>>> {
+    let unique_antennas: HashSet<char> = antenna_grid
+        .cells
+        .iter()
+        .filter_map(|(_, cell)| cell.antenna)
+        .collect();
+
+    if unique_antennas.len() <= 1 {
+        return Err(PuzzleError::unsolvable("Not enough antennas").into());
+    }
+
+    let antennas: HashMap<char, Vec<&AntennaCell>> = unique_antennas
+        .iter()
+        .map(|&antenna| {
+            let cells: Vec<&AntennaCell> = antenna_grid
+                .cells
+                .iter()
+                .map(|(_, cell)| cell)
+                .filter(|cell| cell.antenna == Some(antenna))
+                .collect();
+            (antenna, cells)
+        })
+        .collect();
+
+    Ok(antennas)
+}
+
+fn build_pairs<'a>(antennas: &'a HashMap<char, Vec<&'a AntennaCell>>) -> Result<HashMap<char, Vec<(&'a AntennaCell, &'a AntennaCell)>>> {
+    let antenna_pairs: HashMap<char, Vec<(&AntennaCell, &AntennaCell)>> = antennas
+        .iter()
+        .filter_map(|(antenna, cells)| {
+            if cells.len() < 2 {
+                return None;
+            }
+            let cell_pairs: Vec<(&AntennaCell, &AntennaCell)> = (0..cells.len() - 1)
+                .flat_map(|k| -> Vec<(&AntennaCell, &AntennaCell)> {
+                    (k + 1..cells.len()).map(|l| (cells[k], cells[l])).collect()
+                })
+                .collect();
+            Some((*antenna, cell_pairs))
+        })
+        .collect();
+
+    ensure!(
+        antenna_pairs.values().all(|pairs| !pairs.is_empty()),
+        "No antenna pairs"
+    );
+
+    Ok(antenna_pairs)
+}
+
+/// Points from `start` (included) repeatedly moved by `step`, until they leave the grid.
+fn points_in_line(start: Point, step: Vector, bounds: (usize, usize)) -> Vec<Point> {
+    let (n_rows, n_cols) = bounds;
+    std::iter::successors(Some(start), |point| {
+        point.checked_add_within(step, n_cols, n_rows)
+    })
+    .collect()
+}
+
+fn exercise_2(antenna_pairs: &HashMap<char, Vec<(&AntennaCell, &AntennaCell)>>, bounds: (usize, usize)) -> Result<usize> {
+    let antinodes: HashSet<Point> = antenna_pairs
+        .values()
+        .flat_map(|pairs| -> Vec<Point> {
+            pairs
+                .iter()
+                .flat_map(|(cell_1, cell_2)| -> Vec<Point> {
+                    let (p_1, p_2) = (Point::from(cell_1.coords), Point::from(cell_2.coords));
+                    let delta = p_2 - p_1;
+                    // Both antennas are antinodes themselves: walk back from the first one and
+                    // forward from the second one.
+                    let mut antinodes = points_in_line(p_1, -delta, bounds);
+                    antinodes.extend(points_in_line(p_2, delta, bounds));
+                    antinodes
+                })
+                .collect()
+        })
+        .collect();
+
+    Ok(antinodes.len())
+}
+
+fn exercise_1(antenna_pairs: &HashMap<char, Vec<(&AntennaCell, &AntennaCell)>>, bounds: (usize, usize)) -> Result<usize> {
+    let (n_rows, n_cols) = bounds;
+
+    let antinodes: HashSet<Point> = antenna_pairs
+        .values()
+        .flat_map(|pairs| -> Vec<Point> {
+            pairs
+                .iter()
+                .flat_map(|(cell_1, cell_2)| -> [Option<Point>; 2] {
+                    let (p_1, p_2) = (Point::from(cell_1.coords), Point::from(cell_2.coords));
+                    let delta = p_2 - p_1;
+                    [
+                        p_1.checked_add_within(-delta, n_cols, n_rows),
+                        p_2.checked_add_within(delta, n_cols, n_rows),
+                    ]
+                })
+                .flatten()
+                .collect()
+        })
+        .collect();
+
+    Ok(antinodes.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::*;
+
+    #[fixture]
+    fn sample_input_string() -> &'static str {
+        "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............"
+    }
+
+    #[fixture] 
+    fn sample_antennas() -> HashMap<char, Vec<&'static AntennaCell>> {
+        HashMap::from([
+            ('0', vec![
+                &AntennaCell { antenna: Some('0'), coords: (8, 1) },
+                &AntennaCell { antenna: Some('0'), coords: (5, 2) },
+                &AntennaCell { antenna: Some('0'), coords: (7, 3) },
+                &AntennaCell { antenna: Some('0'), coords: (4, 4) },
+            ]),
+            ('A', vec![
+                &AntennaCell { antenna: Some('A'), coords: (6, 5) },
+                &AntennaCell { antenna: Some('A'), coords: (8, 8) },
+                &AntennaCell { antenna: Some('A'), coords: (9, 9) },
+            ]),
+        ])
+    }
+
+    #[fixture]
+    fn sample_antenna_pairs() -> HashMap<char, Vec<(&'static AntennaCell, &'static AntennaCell)>> {
+        HashMap::from([
+            ('0', vec![
+                (&AntennaCell { antenna: Some('0'), coords: (8, 1) }, &AntennaCell { antenna: Some('0'), coords: (5, 2) }),
+                (&AntennaCell { antenna: Some('0'), coords: (8, 1) }, &AntennaCell { antenna: Some('0'), coords: (7, 3) }),
+                (&AntennaCell { antenna: Some('0'), coords: (8, 1) }, &AntennaCell { antenna: Some('0'), coords: (4, 4) }),
+                (&AntennaCell { antenna: Some('0'), coords: (5, 2) }, &AntennaCell { antenna: Some('0'), coords: (7, 3) }),
+                (&AntennaCell { antenna: Some('0'), coords: (5, 2) }, &AntennaCell { antenna: Some('0'), coords: (4, 4) }),
+                (&AntennaCell { antenna: Some('0'), coords: (7, 3) }, &AntennaCell { antenna: Some('0'), coords: (4, 4) }),
+            ]),
+            ('A', vec![
+                (&AntennaCell { antenna: Some('A'), coords: (6, 5) }, &AntennaCell { antenna: Some('A'), coords: (8, 8) }),
+                (&AntennaCell { antenna: Some('A'), coords: (6, 5) }, &AntennaCell { antenna: Some('A'), coords: (9, 9) }),
+                (&AntennaCell { antenna: Some('A'), coords: (8, 8) }, &AntennaCell { antenna: Some('A'), coords: (9, 9) }),
+            ]),
+        ])
+    }
+
+    #[rstest]
+    fn test_preprocessing(#[from(sample_input_string)] input_str: &'static str, #[from(sample_antennas)] expected_antennas: HashMap<char, Vec<&'static AntennaCell>>, #[from(sample_antenna_pairs)] expected_antenna_pairs: HashMap<char, Vec<(&'static AntennaCell, &'static AntennaCell)>>) {
+        let antenna_grid = AntennaGrid::from_str(input_str).unwrap();
+        let antennas = preprocessing(&antenna_grid).unwrap();
+        assert_eq!(antennas, expected_antennas);
+
+        let antenna_pairs = build_pairs(&antennas).unwrap();
+        assert_eq!(antenna_pairs, expected_antenna_pairs);
+    }
+
+    #[rstest]
+    fn test_exercise_1(#[from(sample_antenna_pairs)] antenna_pairs: HashMap<char, Vec<(&AntennaCell, &AntennaCell)>>) {
+        let result = exercise_1(&antenna_pairs, (12, 12)).unwrap();
+        assert_eq!(result, 14);
+    }
+
+    #[rstest]
+    fn test_exercise_2(#[from(sample_antenna_pairs)] antenna_pairs: HashMap<char, Vec<(&AntennaCell, &AntennaCell)>>) {
+        let result = exercise_2(&antenna_pairs, (12, 12)).unwrap();
+        assert_eq!(result, 34);
+    }
+
+}
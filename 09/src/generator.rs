@@ -0,0 +1,36 @@
+//! Random disk maps.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A disk map of `size` files (at least 2) of 1 to 9 blocks, separated by 0 to 9 free blocks with
+/// at least one free block after the first file.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut input = String::new();
+    for file in 0..size.max(2) {
+        if file > 0 {
+            let free = rng.random_range(u32::from(file == 1)..10);
+            input.push(char::from_digit(free, 10).unwrap());
+        }
+        input.push(char::from_digit(rng.random_range(1..10), 10).unwrap());
+    }
+    input.push('\n');
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day09;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(500, 9);
+        assert_eq!(input, generate(500, 9));
+        assert_eq!(input.trim().len(), 999);
+        assert!(Day09.part_1(&input).is_ok());
+        assert!(Day09.part_2(&input).is_ok());
+        assert!(Day09.part_1(&generate(0, 9)).is_ok());
+    }
+}
@@ -0,0 +1,41 @@
+//! Random topographic maps.
+
+use grid::Grid;
+use rand::{rngs::StdRng, seq::IteratorRandom, Rng, SeedableRng};
+
+/// A square map of side `size` (at least 2) of random heights, with a hiking trail from 0 to 9
+/// drawn from every tenth cell.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(2);
+    let mut map = Grid::new(size, size, 0);
+    for position in map.positions().collect::<Vec<_>>() {
+        map[position] = rng.random_range(0..10);
+    }
+
+    for _ in 0..(size * size / 10).max(1) {
+        let mut position = (rng.random_range(0..size), rng.random_range(0..size));
+        for height in 0..10 {
+            map[position] = height;
+            position = map.neighbors4(position).choose(&mut rng).unwrap();
+        }
+    }
+    map.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day10;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(40, 10);
+        assert_eq!(input, generate(40, 10));
+        let score: usize = Day10.part_1(&input).unwrap().parse().unwrap();
+        let rating: usize = Day10.part_2(&input).unwrap().parse().unwrap();
+        assert!(0 < score && score <= rating);
+        assert!(Day10.part_1(&generate(0, 10)).is_ok());
+    }
+}
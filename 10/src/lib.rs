@@ -0,0 +1,289 @@
+pub mod generator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::Solution;
+use grid::Grid;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Hoof It"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(TopographicMap::from_str(input).map(|_| ()))
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(exercise_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(exercise_2(input)?.to_string())
+    }
+}
+
+pub fn exercise_1(input_str: &str) -> Result<usize> {
+    let mut trail_map = TopographicMap::from_str(input_str)?;
+
+    trail_map.calculate_all_trailhead_scores();
+
+    Ok(trail_map
+        .trailhead_scores
+        .iter()
+        .filter_map(|(_, &score)| score)
+        .sum())
+}
+
+pub fn exercise_2(input_str: &str) -> Result<usize> {
+    let mut trail_map = TopographicMap::from_str(input_str)?;
+
+    trail_map.calculate_all_trailhead_ranks();
+
+    Ok(trail_map
+        .trailhead_ranks
+        .iter()
+        .filter_map(|(_, &rank)| rank)
+        .sum())
+}
+
+struct TopographicMap {
+    map: Grid<usize>,
+    trailheads: HashSet<(usize, usize)>,
+    trailhead_scores: HashMap<(usize, usize), Option<usize>>,
+    trailhead_ranks: HashMap<(usize, usize), Option<usize>>,
+}
+
+// const TRAILHEAD_LEVEL: usize = 0;
+const TRAILEND_LEVEL: usize = 9;
+
+impl TopographicMap {
+    fn from_str(input_str: &str) -> Result<Self> {
+        let map = Grid::parse_with(input_str, |_, c| {
+            c.to_digit(10)
+                .map(|digit| digit as usize)
+                .ok_or_else(|| anyhow!("Invalid input for digit {}", c))
+        })?;
+
+        let trailheads: HashSet<(usize, usize)> = map
+            .iter()
+            .filter_map(|(position, &level)| if level == 0 { Some(position) } else { None })
+            .collect();
+
+        let trailhead_scores: HashMap<(usize, usize), Option<usize>> = trailheads
+            .iter()
+            .map(|&trailhead| (trailhead, None))
+            .collect();
+
+        Ok(Self {
+            map,
+            trailheads,
+            trailhead_ranks: trailhead_scores.clone(),
+            trailhead_scores,
+        })
+    }
+
+
+    // fn is_trailhead(&self, (x, y): (usize, usize)) -> bool {
+    //     self.map[y][x] == TRAILHEAD_LEVEL
+    // }
+
+    fn is_trailend(&self, position: (usize, usize)) -> bool {
+        self.map[position] == TRAILEND_LEVEL
+    }
+
+    fn get_level(&self, position: (usize, usize)) -> usize {
+        self.map[position]
+    }
+
+    fn get_neighbors(&self, position: (usize, usize)) -> Vec<(usize, usize)> {
+        let level = self.get_level(position);
+
+        if level == TRAILEND_LEVEL {
+            return vec![];
+        }
+
+        self.map
+            .neighbors4(position)
+            .filter(|&neighbor| self.get_level(neighbor) == level + 1)
+            .collect()
+    }
+
+    fn calculate_trailhead_score(&self, trailhead: (usize, usize)) -> usize {
+        let mut visited = HashSet::new();
+        let mut queue = vec![trailhead];
+        let mut score = 0;
+
+        while let Some(current) = queue.pop() {
+            visited.insert(current);
+
+            if self.is_trailend(current) {
+                score += 1;
+            }
+
+            let neighbors = self.get_neighbors(current);
+            for neighbor in neighbors {
+                if !visited.contains(&neighbor) {
+                    queue.push(neighbor);
+                }
+            }
+        }
+        score
+    }
+
+    fn calculate_trailhead_rank(&self, trailhead: (usize, usize)) -> usize {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::from([trailhead]);
+        let mut score  = 0;
+
+        while let Some(current) = queue.pop_front() {
+            visited.insert(current);
+
+            if self.is_trailend(current) {
+                score += 1;
+            }
+
+            let neighbors = self.get_neighbors(current);
+            for neighbor in neighbors {
+                if !visited.contains(&neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        score
+    }
+
+    fn calculate_all_trailhead_scores(&mut self) {
+        for trailhead in self.trailheads.iter() {
+            let score = self.calculate_trailhead_score(*trailhead);
+            self.trailhead_scores.insert(*trailhead, Some(score));
+        }
+    }
+
+    fn calculate_all_trailhead_ranks(&mut self) {
+        for trailhead in self.trailheads.iter() {
+            let rank = self.calculate_trailhead_rank(*trailhead);
+            self.trailhead_ranks.insert(*trailhead, Some(rank));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[fixture]
+    fn sample_input_str() -> &'static str {
+        "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732"
+    }
+
+    #[fixture]
+    fn expected_output_1() -> usize {
+        36
+    }
+
+    #[fixture]
+    fn expected_output_2() -> usize {
+        81
+    }
+
+    #[fixture]
+    fn expected_map() -> TopographicMap {
+        TopographicMap {
+            map: Grid::from_rows(vec![
+                vec![8, 9, 0, 1, 0, 1, 2, 3], // 0
+                vec![7, 8, 1, 2, 1, 8, 7, 4], // 1
+                vec![8, 7, 4, 3, 0, 9, 6, 5], // 2
+                vec![9, 6, 5, 4, 9, 8, 7, 4], // 3
+                vec![4, 5, 6, 7, 8, 9, 0, 3], // 4
+                vec![3, 2, 0, 1, 9, 0, 1, 2], // 5
+                vec![0, 1, 3, 2, 9, 8, 0, 1], // 6
+                vec![1, 0, 4, 5, 6, 7, 3, 2], // 7
+            ])
+            .unwrap(),
+            trailheads: HashSet::from([
+                (2, 0),
+                (4, 0),
+                (4, 2),
+                (6, 4),
+                (2, 5),
+                (5, 5),
+                (0, 6),
+                (6, 6),
+                (1, 7),
+            ]),
+            trailhead_scores: HashMap::from([
+                ((2, 0), None),
+                ((4, 0), None),
+                ((4, 2), None),
+                ((6, 4), None),
+                ((2, 5), None),
+                ((5, 5), None),
+                ((0, 6), None),
+                ((6, 6), None),
+                ((1, 7), None),
+            ]),
+            trailhead_ranks: HashMap::from([
+                ((2, 0), None),
+                ((4, 0), None),
+                ((4, 2), None),
+                ((6, 4), None),
+                ((2, 5), None),
+                ((5, 5), None),
+                ((0, 6), None),
+                ((6, 6), None),
+                ((1, 7), None),
+            ]),
+
+        }
+    }
+
+    #[rstest]
+    fn test_input_parsing(
+        #[from(sample_input_str)] input: &str,
+        #[from(expected_map)] expected: TopographicMap,
+    ) {
+        let result = TopographicMap::from_str(input).unwrap();
+        assert_eq!(result.map, expected.map);
+        assert_eq!(result.map.height(), 8);
+        assert_eq!(result.map.width(), 8);
+        assert_eq!(result.trailheads, expected.trailheads);
+    }
+
+    #[rstest]
+    fn test_exercise_1(
+        #[from(sample_input_str)] input: &str,
+        #[from(expected_output_1)] expected: usize,
+    ) {
+        let result = exercise_1(input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn test_exercise_2(
+        #[from(sample_input_str)] input: &str,
+        #[from(expected_output_2)] expected: usize,
+    ) {
+        let result = exercise_2(input).unwrap();
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,28 @@
+//! Random pebble arrangements.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A line of `size` pebbles (at least 1), engraved with numbers below a million. With more than
+/// about 100000 pebbles, the count of the second part overflows.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let pebbles: Vec<String> = (0..size.max(1))
+        .map(|_| rng.random_range(0..1_000_000usize).to_string())
+        .collect();
+    pebbles.join(" ") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day11;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(1000, 11);
+        assert_eq!(input, generate(1000, 11));
+        assert_eq!(input.split_whitespace().count(), 1000);
+        assert!(Day11.part_2(&input).is_ok());
+    }
+}
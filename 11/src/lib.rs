@@ -0,0 +1,337 @@
+pub mod generator;
+
+use anyhow::Result;
+use aoc_common::{PuzzleError, PuzzleResult, Solution};
+use std::collections::HashMap;
+use std::iter::IntoIterator;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Plutonian Pebbles"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(Pebbles::from_str(input).map(|_| ()).map_err(Into::into))
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(exercise_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(exercise_2(input)?.to_string())
+    }
+}
+
+pub fn exercise_1(input_str: &str) -> Result<usize> {
+    let pebbles = Pebbles::from_str(input_str)?;
+
+    Ok(pebbles.blink_count_efficient(25)?)
+}
+
+pub fn exercise_2(input_str: &str) -> Result<usize> {
+    let pebbles = Pebbles::from_str(input_str)?;
+
+    Ok(pebbles.blink_count_efficient(75)?)
+}
+
+#[derive(Debug, Clone)]
+enum BlinkResult {
+    One(Pebble),
+    Two(Pebble, Pebble),
+}
+
+impl IntoIterator for BlinkResult {
+    type Item = Pebble;
+    type IntoIter = std::vec::IntoIter<Pebble>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            BlinkResult::One(p) => vec![p].into_iter(),
+            BlinkResult::Two(p_1, p_2) => vec![p_1, p_2].into_iter(),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Eq, std::hash::Hash, Clone)]
+struct Pebble(usize);
+
+impl Pebble {
+    fn num_digits(&self) -> usize {
+        match usize::checked_ilog10(self.0) {
+            Some(n) => (n as usize) + 1,
+            None => 1,
+        }
+    }
+
+    /// `None` if the engraved number overflows.
+    fn blink(&self) -> Option<BlinkResult> {
+        if self.0 == 0 {
+            return Some(BlinkResult::One(Pebble(1)));
+        }
+
+        let c = self.num_digits();
+        if !c.is_multiple_of(2) {
+            return self
+                .0
+                .checked_mul(2024)
+                .map(|n| BlinkResult::One(Pebble(n)));
+        }
+
+        let mid_c = c / 2;
+
+        let mid_digits = usize::pow(10, mid_c as u32);
+
+        let left = self.0 / mid_digits;
+        let right = self.0 % mid_digits;
+
+        Some(BlinkResult::Two(Pebble(left), Pebble(right)))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Pebbles {
+    pebbles: Vec<Pebble>,
+}
+
+fn blink_k_times_increment(
+    pebble: Pebble,
+    k: usize,
+    blink_results_memo: &mut HashMap<Pebble, BlinkResult>,
+    blink_increments_memo: &mut HashMap<(usize, Pebble), usize>,
+) -> Option<usize> {
+    // Base case, no blinks, no increments.
+    if k == 0 {
+        return Some(0);
+    }
+
+    // Check if we already have the result for blinking this pebble k times.
+    if let Some(&increment) = blink_increments_memo.get(&(k, pebble.clone())) {
+        return Some(increment);
+    }
+
+    // Get the result of blinking the pebble once.
+    let result = if let Some(b_r) = blink_results_memo.get(&pebble) {
+        b_r.to_owned()
+    } else {
+        let result = pebble.blink()?;
+        blink_results_memo.insert(pebble.clone(), result.clone());
+        result
+    };
+
+    // If k = 1, we can return the increment depending on the result.
+    if k == 1 {
+        match result {
+            BlinkResult::One(_) => {
+                blink_increments_memo.insert((k, pebble.clone()), 0);
+                return Some(0);
+            }
+            BlinkResult::Two(_, _) => {
+                blink_increments_memo.insert((k, pebble.clone()), 1);
+                return Some(1);
+            }
+        }
+    }
+
+    // Otherwise, we need to recurse.
+    match result {
+        BlinkResult::One(p) => {
+            // Calculate the increment for blinking k - 1 times.
+            let increment =
+                blink_k_times_increment(p, k - 1, blink_results_memo, blink_increments_memo)?;
+            // After calculating the increment, we can store it in the memoization hashmap for future use.
+            blink_increments_memo.insert((k, pebble.clone()), increment);
+            Some(increment)
+        }
+        BlinkResult::Two(p_1, p_2) => {
+            let increment_1 =
+                blink_k_times_increment(p_1, k - 1, blink_results_memo, blink_increments_memo)?;
+            let increment_2 =
+                blink_k_times_increment(p_2, k - 1, blink_results_memo, blink_increments_memo)?;
+            let increment = increment_1.checked_add(increment_2)?.checked_add(1)?; // Notice the +1 here.
+            blink_increments_memo.insert((k, pebble.clone()), increment);
+            Some(increment)
+        }
+    }
+}
+
+impl Pebbles {
+    fn from_str(input_str: &str) -> PuzzleResult<Self> {
+        let pebbles: PuzzleResult<Vec<Pebble>> = input_str
+            .split_whitespace()
+            .map(|c| -> PuzzleResult<Pebble> {
+                let i = c
+                    .parse::<usize>()
+                    .map_err(|e| PuzzleError::malformed_slice(input_str, c, e))?;
+                Ok(Pebble(i))
+            })
+            .collect();
+        let pebbles = pebbles?;
+        Ok(Self { pebbles })
+    }
+
+    /// Fails if a number or the count of pebbles overflows.
+    fn blink_count_efficient(self, n: usize) -> PuzzleResult<usize> {
+        // The number of final pebbles. Must start with the initial pebbles.
+        let initial_count = self.pebbles.len();
+
+        // Use these hashmaps to memoize the results.
+        let mut blink_results: HashMap<Pebble, BlinkResult> = HashMap::new();
+        let mut blink_increments: HashMap<(usize, Pebble), usize> = HashMap::new();
+
+        self.pebbles
+            .into_iter()
+            .try_fold(initial_count, |acc, pebble| {
+                acc.checked_add(blink_k_times_increment(
+                    pebble,
+                    n,
+                    &mut blink_results,
+                    &mut blink_increments,
+                )?)
+            })
+            .ok_or_else(|| PuzzleError::unsolvable(format!("Pebbles overflow after {} blinks", n)))
+    }
+
+    // fn blink(self, n: usize) -> usize {
+    //     // Use this hashmap to memoize the results.
+    //     let mut blink_results: HashMap<Pebble, BlinkResult> = HashMap::new();
+    //     // Insert base case.
+    //     blink_results.insert(Pebble(0), BlinkResult::One(Pebble(1)));
+
+    //     self.pebbles
+    //         .into_iter()
+    //         .map(|pebble| {
+    //             let pebbles = (0..n).fold(vec![pebble], |pebbles, _| {
+    //                 pebbles
+    //                     .into_iter()
+    //                     .flat_map(|p| {
+    //                         let result = if let Some(&ref b_r) = blink_results.get(&p) {
+    //                             b_r.to_owned()
+    //                         } else {
+    //                             let result = p.blink();
+    //                             blink_results.insert(p.clone(), result.clone());
+    //                             result
+    //                         };
+    //                         result
+    //                     })
+    //                     .collect()
+    //             });
+    //             pebbles.len()
+    //         })
+    //         .sum()
+    // }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use rstest::*;
+
+    /// Applies the rules to every pebble, `k` times, working on the engraved digits.
+    fn brute_force_blink_count(pebbles: &[usize], k: usize) -> usize {
+        let mut pebbles = pebbles.to_vec();
+        for _ in 0..k {
+            pebbles = pebbles
+                .into_iter()
+                .flat_map(|n| {
+                    let digits = n.to_string();
+                    if n == 0 {
+                        vec![1]
+                    } else if digits.len() % 2 == 0 {
+                        let (left, right) = digits.split_at(digits.len() / 2);
+                        vec![left.parse().unwrap(), right.parse().unwrap()]
+                    } else {
+                        vec![n * 2024]
+                    }
+                })
+                .collect();
+        }
+        pebbles.len()
+    }
+
+    proptest! {
+        #[test]
+        fn prop_blink_k_times_increment_matches_brute_force(n in 0..1_000_000usize, k in 0..16usize) {
+            let increment = blink_k_times_increment(Pebble(n), k, &mut HashMap::new(), &mut HashMap::new());
+            prop_assert_eq!(increment, Some(brute_force_blink_count(&[n], k) - 1));
+        }
+
+        #[test]
+        fn prop_blink_count_efficient_matches_brute_force(
+            numbers in prop::collection::vec(prop_oneof![0..10usize, 0..1_000_000usize], 1..6),
+            k in 0..12usize,
+        ) {
+            let pebbles = Pebbles { pebbles: numbers.iter().map(|&n| Pebble(n)).collect() };
+            prop_assert_eq!(pebbles.blink_count_efficient(k).unwrap(), brute_force_blink_count(&numbers, k));
+        }
+    }
+
+    #[fixture]
+    fn sample_input() -> &'static str {
+        "125 17"
+    }
+
+    #[fixture]
+    fn sample_pebbles() -> Pebbles {
+        Pebbles {
+            pebbles: vec![Pebble(125), Pebble(17)],
+        }
+    }
+
+    #[fixture]
+    fn exptected_output() -> usize {
+        55312
+    }
+
+    #[fixture]
+    fn exptected_output_2() -> usize {
+        65601038650482
+    }
+
+    #[rstest]
+    fn test_from_str(#[from(sample_input)] input: &str, #[from(sample_pebbles)] expected: Pebbles) {
+        let pebbles = Pebbles::from_str(input).unwrap();
+        assert_eq!(pebbles, expected);
+    }
+
+    #[rstest]
+    fn test_errors() {
+        assert_eq!(
+            Pebbles::from_str("125 1x7").unwrap_err().to_string(),
+            "Line 1, column 5: invalid digit found in string\n1 | 125 1x7\n  |     ^^^"
+        );
+        let huge = Pebbles::from_str("1000000000000000000").unwrap();
+        assert!(matches!(
+            huge.blink_count_efficient(3),
+            Err(PuzzleError::Unsolvable(_))
+        ));
+    }
+    #[rstest]
+    fn test_exercise_1(
+        #[from(sample_input)] input: &str,
+        #[from(exptected_output)] expected: usize,
+    ) {
+        let result = exercise_1(input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn test_exercise_2(
+        #[from(sample_input)] input: &str,
+        #[from(exptected_output_2)] expected: usize,
+    ) {
+        let result = exercise_2(input).unwrap();
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,53 @@
+//! Random garden plots.
+
+use grid::Grid;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::VecDeque;
+
+/// A square garden of side `size` (at least 1), split into irregular regions grown from one seed
+/// plot every 20 cells. Neighbouring regions may share a plant type, and then merge.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+    let mut garden: Grid<Option<char>> = Grid::new(size, size, None);
+
+    let mut queue = VecDeque::new();
+    for _ in 0..(size * size / 20).max(1) {
+        let position = (rng.random_range(0..size), rng.random_range(0..size));
+        garden[position] = Some(char::from(rng.random_range(b'A'..=b'Z')));
+        queue.push_back(position);
+    }
+    while let Some(position) = queue.pop_front() {
+        let neighbors: Vec<_> = garden
+            .neighbors4(position)
+            .filter(|&n| garden[n].is_none())
+            .collect();
+        for neighbor in neighbors {
+            // Leaving some neighbors to other regions makes the borders ragged.
+            if rng.random_bool(0.7) {
+                garden[neighbor] = garden[position];
+                queue.push_back(neighbor);
+            } else {
+                queue.push_back(position);
+            }
+        }
+    }
+    garden.map(|plant| plant.unwrap()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day12;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(50, 12);
+        assert_eq!(input, generate(50, 12));
+        let price: usize = Day12.part_1(&input).unwrap().parse().unwrap();
+        let discounted: usize = Day12.part_2(&input).unwrap().parse().unwrap();
+        assert!(0 < discounted && discounted < price);
+        assert!(Day12.part_1(&generate(0, 12)).is_ok());
+    }
+}
@@ -0,0 +1,43 @@
+//! Random claw machines.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// `size` claw machines (at least 1) with buttons moving the claw by 10 to 99 on each axis. Half
+/// of the prizes are reachable with at most 100 presses of each button, the others are at random
+/// positions.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let machines: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let [ax, ay, bx, by]: [usize; 4] = std::array::from_fn(|_| rng.random_range(10..100));
+            let (px, py) = if rng.random_bool(0.5) {
+                let (a, b) = (rng.random_range(0..=100), rng.random_range(0..=100));
+                (a * ax + b * bx, a * ay + b * by)
+            } else {
+                (rng.random_range(0..20_000), rng.random_range(0..20_000))
+            };
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                ax, ay, bx, by, px, py
+            )
+        })
+        .collect();
+    machines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day13;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(200, 13);
+        assert_eq!(input, generate(200, 13));
+        assert_eq!(input.matches("Prize").count(), 200);
+        let tokens: usize = Day13.part_1(&input).unwrap().parse().unwrap();
+        assert!(tokens > 0);
+        assert!(Day13.part_2(&input).is_ok());
+    }
+}
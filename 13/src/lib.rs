@@ -0,0 +1,295 @@
+pub mod generator;
+
+use anyhow::Result;
+use aoc_common::{PuzzleError, PuzzleResult, Scanner, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn title(&self) -> &'static str {
+        "Claw Contraption"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(input.split("\n\n").try_for_each(|s| {
+            System::parse(input, s)?;
+            System::parse_added(input, s)?;
+            Ok(())
+        }))
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(exercise_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(exercise_2(input)?.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct System {
+    // matrix[0][0] * x[0] + matrix[0][1] * x[1] = b[0]
+    // matrix[1][0] * x[0] + matrix[1][1] * x[1] = b[1]
+    // a = matrix[0][0], b = matrix[0][1], c = matrix[1][0], d = matrix[1][1]
+    matrix: [[usize; 2]; 2],
+    b: [usize; 2],
+    x: Option<[usize; 2]>,
+}
+
+impl System {
+    fn new(matrix: [[usize; 2]; 2], b: [usize; 2]) -> Self {
+        Self { matrix, b, x: None }
+    }
+
+    #[cfg(test)]
+    fn from_str(input_str: &str) -> PuzzleResult<Self> {
+        Self::parse(input_str, input_str)
+    }
+
+    /// Parses the `block` of `input` describing one claw machine, reporting errors at their
+    /// position in the whole input.
+    fn parse(input: &str, block: &str) -> PuzzleResult<Self> {
+        let mut scanner = Scanner::within(input, block);
+        let mut numbers = [0; 6];
+        for (i, label) in ["Button A: X+", "Button B: X+", "Prize: X="]
+            .iter()
+            .enumerate()
+        {
+            if i > 0 {
+                scanner.literal("\n")?;
+            }
+            scanner.literal(label)?;
+            numbers[2 * i] = scanner.number()?;
+            scanner.literal(if i < 2 { ", Y+" } else { ", Y=" })?;
+            numbers[2 * i + 1] = scanner.number()?;
+        }
+        scanner.finish()?;
+
+        Ok(Self::new(
+            [[numbers[0], numbers[2]], [numbers[1], numbers[3]]],
+            [numbers[4], numbers[5]],
+        ))
+    }
+
+    fn parse_added(input: &str, block: &str) -> PuzzleResult<Self> {
+        let mut system = Self::parse(input, block)?;
+
+        for b in &mut system.b {
+            *b = b.checked_add(10_000_000_000_000).ok_or_else(|| {
+                PuzzleError::malformed_slice(input, block, "Prize coordinates overflow")
+            })?;
+        }
+
+        Ok(system)
+    }
+
+    /// `None` if it overflows.
+    fn det(&self) -> Option<i128> {
+        // a * d - b * c
+        let [[a, b], [c, d]] = self.matrix.map(|row| row.map(|n| n as i128));
+        cross(a, d, b, c)
+    }
+
+    fn solve(&mut self) -> bool {
+        let det = match self.det() {
+            Some(0) | None => return false,
+            Some(det) => det,
+        };
+
+        // Calculate the solution, in 128 bits.
+        // x[0] = (d * b[0] - b * b[1]) / det
+        // x[1] = (a * b[1] - c * b[0]) / det
+        let [[a, b], [c, d]] = self.matrix.map(|row| row.map(|n| n as i128));
+        let [b_0, b_1] = self.b.map(|n| n as i128);
+        let (Some(x_p_0), Some(x_p_1)) = (cross(d, b_0, b, b_1), cross(a, b_1, c, b_0)) else {
+            return false;
+        };
+        let mut x_p = [x_p_0, x_p_1];
+
+        // Check if the solution is an integer.
+        if x_p[0] % det != 0 || x_p[1] % det != 0 {
+            return false;
+        }
+
+        x_p[0] /= det;
+        x_p[1] /= det;
+
+        // Check if the solution is positive, and small enough to be a number of presses.
+        let (Ok(x_0), Ok(x_1)) = (usize::try_from(x_p[0]), usize::try_from(x_p[1])) else {
+            return false;
+        };
+
+        // Set a successful solution.
+        self.x = Some([x_0, x_1]);
+        true
+    }
+
+    fn calculate_cost(&self, threshold: Option<usize>) -> Option<usize> {
+        if let Some(x) = self.x {
+            if let Some(t) = threshold {
+                if x[0] > t || x[1] > t {
+                    None
+                } else {
+                    x[0].checked_mul(3)?.checked_add(x[1])
+                }
+            } else {
+                x[0].checked_mul(3)?.checked_add(x[1])
+            }
+        } else {
+            None
+        }
+    }
+}
+
+/// `p * q - r * s`, `None` if it overflows.
+fn cross(p: i128, q: i128, r: i128, s: i128) -> Option<i128> {
+    p.checked_mul(q)?.checked_sub(r.checked_mul(s)?)
+}
+
+pub fn exercise_1(input_str: &str) -> Result<usize> {
+    let systems: PuzzleResult<Vec<System>> = input_str
+        .split("\n\n")
+        .map(|s| System::parse(input_str, s))
+        .collect();
+    let mut systems = systems?;
+
+    let scores: Vec<usize> = systems
+        .iter_mut()
+        .flat_map(|s| {
+            if !s.solve() {
+                return None;
+            }
+            s.calculate_cost(Some(100))
+        })
+        .collect();
+
+    Ok(scores.iter().sum())
+}
+
+pub fn exercise_2(input_str: &str) -> Result<usize> {
+    let systems: PuzzleResult<Vec<System>> = input_str
+        .split("\n\n")
+        .map(|s| System::parse_added(input_str, s))
+        .collect();
+    let mut systems = systems?;
+
+    let scores: Vec<usize> = systems
+        .iter_mut()
+        .flat_map(|s| {
+            if !s.solve() {
+                return None;
+            }
+            s.calculate_cost(None)
+        })
+        .collect();
+
+    Ok(scores.iter().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use rstest::*;
+
+    /// The cheapest way to win with at most `max_presses` presses of each button, trying them all.
+    fn brute_force_cost(system: &System, max_presses: usize) -> Option<usize> {
+        let [[ax, bx], [ay, by]] = system.matrix;
+        (0..=max_presses)
+            .flat_map(|a| (0..=max_presses).map(move |b| (a, b)))
+            .filter(|&(a, b)| a * ax + b * bx == system.b[0] && a * ay + b * by == system.b[1])
+            .map(|(a, b)| 3 * a + b)
+            .min()
+    }
+
+    /// Buttons and a prize that is reachable half of the time.
+    fn claw_machine() -> impl Strategy<Value = System> {
+        (
+            [[1..100usize, 1..100usize], [1..100usize, 1..100usize]],
+            0..=120usize,
+            0..=120usize,
+            any::<bool>(),
+            [0..20_000usize, 0..20_000usize],
+        )
+            .prop_map(|(matrix, a, b, reachable, random)| {
+                let [[ax, bx], [ay, by]] = matrix;
+                let prize = if reachable {
+                    [a * ax + b * bx, a * ay + b * by]
+                } else {
+                    random
+                };
+                System::new(matrix, prize)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_solve_matches_brute_force(mut system in claw_machine()) {
+            // With collinear buttons there can be several solutions, which `solve` does not handle.
+            prop_assume!(system.det() != Some(0));
+            let expected = brute_force_cost(&system, 100);
+            let cost = if system.solve() { system.calculate_cost(Some(100)) } else { None };
+            prop_assert_eq!(cost, expected);
+            if let Some([a, b]) = system.x {
+                let [[ax, bx], [ay, by]] = system.matrix;
+                prop_assert_eq!([a * ax + b * bx, a * ay + b * by], system.b);
+            }
+        }
+    }
+
+    #[fixture]
+    fn sample_input_str() -> &'static str {
+        "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400"
+    }
+
+    #[fixture]
+    fn expected_input() -> System {
+        System::new([[94, 22], [34, 67]], [8400, 5400])
+    }
+
+    #[fixture]
+    fn expected_output() -> usize {
+        280
+    }
+
+    #[rstest]
+    fn test_system_from_str(sample_input_str: &str, expected_input: System) {
+        let system = System::from_str(sample_input_str).unwrap();
+        assert_eq!(system, expected_input);
+    }
+
+    #[rstest]
+    fn test_cost_calculation(expected_input: System, expected_output: usize) {
+        let mut system = expected_input;
+        system.solve();
+        assert_eq!(system.calculate_cost(Some(100)), Some(expected_output));
+    }
+
+    #[rstest]
+    fn test_parse_errors(sample_input_str: &str) {
+        let input = format!(
+            "{}\n\nButton A: X+1, Y+2\nPrize: X=3, Y=4",
+            sample_input_str
+        );
+        assert_eq!(
+            exercise_1(&input).unwrap_err().to_string(),
+            "Line 6, column 1: Expected \"Button B: X+\"\n6 | Prize: X=3, Y=4\n  | ^"
+        );
+        let input = "Button A: X+1, Y+2\nButton B: X+3, Y-4\nPrize: X=5, Y=6\n";
+        assert_eq!(
+            exercise_1(input).unwrap_err().to_string(),
+            "Line 2, column 17: Expected \", Y+\"\n2 | Button B: X+3, Y-4\n  |                 ^"
+        );
+    }
+}
@@ -0,0 +1,61 @@
+//! Random guards on the 101x103 map, hiding a picture.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const WIDTH: i64 = 101;
+const HEIGHT: i64 = 103;
+/// The picture is the border of a rectangle this large, dense enough for the second part.
+const FRAME: (i64, i64) = (31, 33);
+
+/// `size` guards (at least enough for the picture), a frame of whom line up after a random number
+/// of seconds. With a few thousand guards or more, random rows and columns get dense as well.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let time = rng.random_range(0..WIDTH * HEIGHT);
+    let (left, top) = (
+        rng.random_range(0..WIDTH - FRAME.0),
+        rng.random_range(0..HEIGHT - FRAME.1),
+    );
+    let mut targets: Vec<Option<(i64, i64)>> = Vec::new();
+    for x in 0..FRAME.0 {
+        targets.push(Some((left + x, top)));
+        targets.push(Some((left + x, top + FRAME.1 - 1)));
+    }
+    for y in 1..FRAME.1 - 1 {
+        targets.push(Some((left, top + y)));
+        targets.push(Some((left + FRAME.0 - 1, top + y)));
+    }
+    targets.resize(size.max(targets.len()), None);
+
+    let mut input = String::new();
+    for target in targets {
+        let (vx, vy) = (rng.random_range(-99..100), rng.random_range(-99..100));
+        let (px, py) = match target {
+            Some((x, y)) => (
+                (x - vx * time).rem_euclid(WIDTH),
+                (y - vy * time).rem_euclid(HEIGHT),
+            ),
+            None => (rng.random_range(0..WIDTH), rng.random_range(0..HEIGHT)),
+        };
+        input.push_str(&format!("p={},{} v={},{}\n", px, py, vx, vy));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day14;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(200, 14);
+        assert_eq!(input, generate(200, 14));
+        assert_eq!(input.lines().count(), 200);
+        assert!(Day14.part_1(&input).is_ok());
+        let time: i64 = Day14.part_2(&input).unwrap().parse().unwrap();
+        assert!(time < WIDTH * HEIGHT);
+        assert_eq!(generate(0, 14).lines().count(), 124);
+    }
+}
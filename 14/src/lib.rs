@@ -0,0 +1,379 @@
+pub mod generator;
+
+use anyhow::Result;
+use aoc_common::{Part, PuzzleError, PuzzleResult, Scanner, Solution};
+use std::{collections::HashSet, io::BufRead};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn title(&self) -> &'static str {
+        "Restroom Redoubt"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(for_each_guard(input.as_bytes(), 101, 103, |_| ()).map_err(Into::into))
+    }
+
+    fn solve_reader(&self, part: Part, input: &mut dyn BufRead) -> Option<Result<String>> {
+        let answer = match part {
+            Part::One => exercise_1(input, 101, 103, 100).map(|n| n.to_string()),
+            Part::Two => exercise_2(input, 101, 103, 1_000_000).map(|n| n.to_string()),
+        };
+        Some(answer.map_err(Into::into))
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(exercise_1(input.as_bytes(), 101, 103, 100)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(exercise_2(input.as_bytes(), 101, 103, 1_000_000)?.to_string())
+    }
+}
+
+/// Calls `f` on every guard as soon as its line is read.
+fn for_each_guard<F>(
+    buffered: impl BufRead,
+    width: usize,
+    height: usize,
+    mut f: F,
+) -> PuzzleResult<()>
+where
+    F: FnMut(Guard),
+{
+    for (index, line) in buffered.lines().enumerate() {
+        let line = line?;
+        let guard = Guard::parse(index, &line)?;
+        let [x, y] = guard.position;
+        if x >= width || y >= height {
+            let position = line.split(' ').next().unwrap_or(&line);
+            return Err(PuzzleError::malformed_token(
+                index,
+                &line,
+                position,
+                format!("Position is outside of the {}x{} map", width, height),
+            ));
+        }
+        f(guard);
+    }
+    Ok(())
+}
+
+/// Guards move independently, so each one is moved to its final position and forgotten.
+pub fn exercise_1(
+    buffered: impl BufRead,
+    width: usize,
+    height: usize,
+    timesteps: usize,
+) -> PuzzleResult<usize> {
+    let mut guards_per_quadrant = [0; 4];
+    for_each_guard(buffered, width, height, |mut guard| {
+        guard.advance_timesteps(timesteps, width, height);
+        if let Some(quadrant) = guard.assign_quadrant(width, height) {
+            guards_per_quadrant[quadrant as usize] += 1;
+        }
+    })?;
+
+    Ok(guards_per_quadrant.iter().product())
+}
+
+/// Unlike the first part, this needs every guard at once to look at whole frames.
+pub fn exercise_2(
+    buffered: impl BufRead,
+    width: usize,
+    height: usize,
+    max_iterations: usize,
+) -> PuzzleResult<usize> {
+    let mut map = Map::read(buffered, width, height)?;
+
+    for k in 0..max_iterations {
+        if map.dense_frame() {
+            return Ok(k);
+        }
+        map.advance_timesteps(1, false);
+    }
+    Err(PuzzleError::unsolvable(format!(
+        "No dense frame found after {} iterations",
+        max_iterations
+    )))
+}
+
+#[derive(Debug, PartialEq)]
+struct Map {
+    width: usize,
+    height: usize,
+    guards: Vec<Guard>,
+}
+
+fn move_cyclically(position: usize, velocity: isize, max: usize) -> usize {
+    // Reducing the velocity first keeps everything within `0..2 * max`.
+    let velocity = velocity.rem_euclid(max as isize) as usize;
+    (position + velocity) % max
+}
+
+impl Map {
+    fn new(width: usize, height: usize, guards: Vec<Guard>) -> Self {
+        Self {
+            width,
+            height,
+            guards,
+        }
+    }
+
+    fn read(buffered: impl BufRead, width: usize, height: usize) -> PuzzleResult<Self> {
+        let mut guards = Vec::new();
+        for_each_guard(buffered, width, height, |guard| guards.push(guard))?;
+        Ok(Map::new(width, height, guards))
+    }
+
+    fn move_guards(&mut self) {
+        for guard in &mut self.guards {
+            let [p_x, p_y] = guard.position;
+            let [v_x, v_y] = guard.velocity;
+            guard.position = [
+                move_cyclically(p_x, v_x, self.width),
+                move_cyclically(p_y, v_y, self.height),
+            ];
+        }
+    }
+
+    fn advance_timesteps(&mut self, n: usize, print: bool) {
+        if print {
+            println!("{}", self);
+        }
+        for _ in 0..n {
+            self.move_guards();
+            if print {
+                println!("{}", self);
+            }
+        }
+    }
+
+    fn dense_row(&self, j: usize) -> bool {
+        let columns: HashSet<usize> = HashSet::from_iter(self.guards.iter().filter_map(|g| {
+            if g.position[1] == j {
+                Some(g.position[0])
+            } else {
+                None
+            }
+        }));
+        columns.len() >= 30
+    }
+
+    fn dense_column(&self, i: usize) -> bool {
+        let rows: HashSet<usize> = HashSet::from_iter(self.guards.iter().filter_map(|g| {
+            if g.position[0] == i {
+                Some(g.position[1])
+            } else {
+                None
+            }
+        }));
+        rows.len() >= 30
+    }
+
+    fn dense_frame(&self) -> bool {
+        (0..self.width).any(|i| self.dense_column(i)) && (0..self.height).any(|j| self.dense_row(j))
+    }
+}
+
+use std::fmt::{self, Display, Formatter};
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut map = vec![vec!['.'; self.width + 2]; self.height + 2];
+        map[0] = vec!['-'; self.width + 2];
+        map[self.height + 1] = vec!['-'; self.width + 2];
+        for row in map.iter_mut().skip(1).take(self.height) {
+            row[0] = '|';
+            row[self.width + 1] = '|';
+        }
+
+        for guard in &self.guards {
+            let [x, y] = guard.position;
+            match map[y + 1][x + 1] {
+                '.' => map[y + 1][x + 1] = '1',
+                '*' => map[y + 1][x + 1] = '*',
+                c => {
+                    let n = c.to_digit(10).unwrap();
+                    if n == 9 {
+                        map[y + 1][x + 1] = '*';
+                    } else {
+                        map[y + 1][x + 1] = char::from_digit(n + 1, 10).unwrap();
+                    }
+                }
+            }
+        }
+
+        for row in map {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Quadrant {
+    NorthWest,
+    NorthEast,
+    SouthEast,
+    SouthWest,
+}
+
+#[derive(Debug, PartialEq)]
+struct Guard {
+    position: [usize; 2],
+    velocity: [isize; 2],
+}
+
+impl Guard {
+    fn new(position: [usize; 2], velocity: [isize; 2]) -> Self {
+        Self { position, velocity }
+    }
+
+    #[cfg(test)]
+    fn from_str(input_str: &str) -> PuzzleResult<Self> {
+        Self::parse(0, input_str)
+    }
+
+    /// Parses a `p=x,y v=x,y` `line`, the `index`-th of the input.
+    fn parse(index: usize, line: &str) -> PuzzleResult<Self> {
+        let mut scanner = Scanner::on_line(index, line);
+        scanner.literal("p=")?;
+        let p_x = scanner.number()?;
+        scanner.literal(",")?;
+        let p_y = scanner.number()?;
+        scanner.literal(" v=")?;
+        let v_x = scanner.number()?;
+        scanner.literal(",")?;
+        let v_y = scanner.number()?;
+        scanner.finish()?;
+
+        Ok(Self::new([p_x, p_y], [v_x, v_y]))
+    }
+
+    fn advance_timesteps(&mut self, n: usize, width: usize, height: usize) {
+        let [p_x, p_y] = self.position;
+        let [v_x, v_y] = self.velocity;
+        // Moving `n` times cycles with the size of the map, and so does the velocity.
+        let travel = |velocity: isize, max: usize| {
+            let velocity = velocity.rem_euclid(max as isize) as u128;
+            (velocity * (n % max) as u128 % max as u128) as isize
+        };
+        self.position = [
+            move_cyclically(p_x, travel(v_x, width), width),
+            move_cyclically(p_y, travel(v_y, height), height),
+        ];
+    }
+
+    fn assign_quadrant(&self, width: usize, height: usize) -> Option<Quadrant> {
+        let (mid_width, mid_height) = (width / 2, height / 2);
+        let [x, y] = self.position;
+        if x == mid_width || y == mid_height {
+            return None;
+        }
+
+        if x < mid_width && y < mid_height {
+            Some(Quadrant::NorthWest)
+        } else if y < mid_height {
+            Some(Quadrant::NorthEast)
+        } else if x > mid_width && y > mid_height {
+            Some(Quadrant::SouthEast)
+        } else {
+            Some(Quadrant::SouthWest)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[fixture]
+    fn sample_line() -> &'static str {
+        "p=0,4 v=3,-3"
+    }
+
+    #[fixture]
+    fn expected_guard() -> Guard {
+        Guard::new([0, 4], [3, -3])
+    }
+
+    #[rstest]
+    fn test_from_str(sample_line: &str, expected_guard: Guard) {
+        assert_eq!(Guard::from_str(sample_line).unwrap(), expected_guard)
+    }
+
+    #[fixture]
+    fn sample_input() -> &'static str {
+        "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3"
+    }
+
+    #[fixture]
+    fn expected_guards() -> Vec<Guard> {
+        vec![
+            Guard::new([0, 4], [3, -3]),
+            Guard::new([6, 3], [-1, -3]),
+            Guard::new([10, 3], [-1, 2]),
+            Guard::new([2, 0], [2, -1]),
+            Guard::new([0, 0], [1, 3]),
+            Guard::new([3, 0], [-2, -2]),
+            Guard::new([7, 6], [-1, -3]),
+            Guard::new([3, 0], [-1, -2]),
+            Guard::new([9, 3], [2, 3]),
+            Guard::new([7, 3], [-1, 2]),
+            Guard::new([2, 4], [2, -3]),
+            Guard::new([9, 5], [-3, -3]),
+        ]
+    }
+
+    #[rstest]
+    fn test_preprocessing(sample_input: &str, expected_guards: Vec<Guard>) {
+        let guards: PuzzleResult<Vec<Guard>> = sample_input.lines().map(Guard::from_str).collect();
+        assert_eq!(guards.unwrap(), expected_guards);
+    }
+
+    #[rstest]
+    #[case(
+        "p=0,4 v=3,-3\np=6,3 v=-1;-3",
+        "Line 2, column 11: Expected \",\"\n2 | p=6,3 v=-1;-3\n  |           ^"
+    )]
+    #[case(
+        "p=-6,3 v=-1,-3",
+        "Line 1, column 3: invalid digit found in string\n1 | p=-6,3 v=-1,-3\n  |   ^^"
+    )]
+    #[case("p=6,3 v=-1,-3 x", "Line 1, column 15: Unexpected trailing characters\n1 | p=6,3 v=-1,-3 x\n  |               ^")]
+    #[case(
+        "p=6,30 v=-1,-3",
+        "Line 1, column 1: Position is outside of the 11x7 map\n1 | p=6,30 v=-1,-3\n  | ^^^^^^"
+    )]
+    fn test_parse_errors(#[case] input: &str, #[case] expected: &str) {
+        let error = Map::read(input.as_bytes(), 11, 7).err().unwrap();
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_exercise_1(sample_input: &str) {
+        assert_eq!(exercise_1(sample_input.as_bytes(), 11, 7, 100).unwrap(), 12);
+    }
+}
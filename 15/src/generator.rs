@@ -0,0 +1,50 @@
+//! Random warehouses and robot moves.
+
+use grid::Grid;
+use rand::{rngs::StdRng, seq::IndexedRandom, Rng, SeedableRng};
+
+/// A square warehouse of side `size` (at least 3) surrounded by walls, with boxes on a fifth and
+/// walls on a twentieth of the floor, followed by ten moves per cell in lines of 1000.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(3);
+    let mut warehouse = Grid::new(size, size, '#');
+    for y in 1..size - 1 {
+        for x in 1..size - 1 {
+            warehouse[(x, y)] = match rng.random_range(0..20) {
+                0..4 => 'O',
+                4 => '#',
+                _ => '.',
+            };
+        }
+    }
+    warehouse[(rng.random_range(1..size - 1), rng.random_range(1..size - 1))] = '@';
+
+    let moves: Vec<u8> = (0..10 * size * size)
+        .map(|_| *b"^>v<".choose(&mut rng).unwrap())
+        .collect();
+    let mut input = warehouse.to_string();
+    for line in moves.chunks(1000) {
+        input.push('\n');
+        input.push_str(std::str::from_utf8(line).unwrap());
+    }
+    input.push('\n');
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day15;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(30, 15);
+        assert_eq!(input, generate(30, 15));
+        assert_eq!(input.matches('@').count(), 1);
+        assert!(Day15.part_1(&input).is_ok());
+        assert!(Day15.part_2(&input).is_ok());
+        assert!(Day15.part_1(&generate(0, 15)).is_ok());
+    }
+}
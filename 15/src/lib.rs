@@ -0,0 +1,757 @@
+pub mod generator;
+
+use anyhow::{anyhow, ensure, Result};
+use aoc_common::{PuzzleError, PuzzleResult, Solution};
+use grid::{Direction, FromChar, Grid, Point};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn title(&self) -> &'static str {
+        "Warehouse Woes"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(
+            preprocessing(input)
+                .and(preprocessing_big(input))
+                .map(|_| ()),
+        )
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(exercise_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(exercise_2(input)?.to_string())
+    }
+}
+
+pub fn exercise_1(input_str: &str) -> Result<usize> {
+    let (mut warehouse, moves) = preprocessing(input_str)?;
+    for mv in &moves {
+        if let Some((x, y)) = warehouse.move_cell(mv, warehouse.robot_location)? {
+            warehouse.robot_location = (x, y);
+        }
+    }
+    Ok(warehouse.calculate_score())
+}
+
+pub fn exercise_2(input_str: &str) -> Result<usize> {
+    let (mut warehouse, moves) = preprocessing_big(input_str)?;
+    for mv in &moves {
+        if let Some((x, y)) = warehouse.move_cell(mv, warehouse.robot_location)? {
+            warehouse.robot_location = (x, y);
+        }
+    }
+    Ok(warehouse.calculate_score())
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum WarehouseCell {
+    Empty,
+    Wall,
+    Box,
+    Robot,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum BigWarehouseCell {
+    Empty,
+    Wall,
+    LeftBox,
+    RightBox,
+    Robot,
+}
+
+impl FromChar for WarehouseCell {
+    fn from_char(c: char) -> Result<Self> {
+        match c {
+            '.' => Ok(Self::Empty),
+            '#' => Ok(Self::Wall),
+            'O' => Ok(Self::Box),
+            '@' => Ok(Self::Robot),
+            _ => Err(anyhow!("Invalid character: {}", c)),
+        }
+    }
+}
+
+impl WarehouseCell {
+    fn movable(&self) -> bool {
+        matches!(self, Self::Box | Self::Robot)
+    }
+}
+
+impl From<WarehouseCell> for char {
+    fn from(val: WarehouseCell) -> Self {
+        match val {
+            WarehouseCell::Empty => '.',
+            WarehouseCell::Wall => '#',
+            WarehouseCell::Box => 'O',
+            WarehouseCell::Robot => '@',
+        }
+    }
+}
+
+impl FromChar for BigWarehouseCell {
+    fn from_char(c: char) -> Result<Self> {
+        match c {
+            '.' => Ok(Self::Empty),
+            '#' => Ok(Self::Wall),
+            '[' => Ok(Self::LeftBox),
+            ']' => Ok(Self::RightBox),
+            '@' => Ok(Self::Robot),
+            _ => Err(anyhow!("Invalid character: {}", c)),
+        }
+    }
+}
+
+impl BigWarehouseCell {
+    fn movable(&self) -> bool {
+        matches!(self, Self::LeftBox | Self::RightBox | Self::Robot)
+    }
+}
+
+impl From<BigWarehouseCell> for char {
+    fn from(val: BigWarehouseCell) -> Self {
+        match val {
+            BigWarehouseCell::Empty => '.',
+            BigWarehouseCell::Wall => '#',
+            BigWarehouseCell::LeftBox => '[',
+            BigWarehouseCell::RightBox => ']',
+            BigWarehouseCell::Robot => '@',
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Warehouse {
+    cells: Grid<WarehouseCell>,
+    robot_location: (usize, usize),
+}
+
+impl FromStr for Warehouse {
+    type Err = anyhow::Error;
+
+    fn from_str(input_str: &str) -> Result<Self> {
+        let mut robot_location: Option<(usize, usize)> = None;
+        let cells = Grid::parse_with(input_str, |position, c| {
+            let c = WarehouseCell::from_char(c)?;
+            if let WarehouseCell::Robot = c {
+                ensure!(robot_location.is_none(), "Second robot");
+                robot_location = Some(position);
+            }
+            Ok(c)
+        })?;
+        check_walls(input_str, 1)?;
+
+        let robot_location =
+            robot_location.ok_or_else(|| PuzzleError::unsolvable("Robot not found"))?;
+
+        Ok(Self {
+            cells,
+            robot_location,
+        })
+    }
+}
+
+impl Warehouse {
+    fn move_cell(
+        &mut self,
+        mv: &Direction,
+        (x, y): (usize, usize),
+    ) -> Result<Option<(usize, usize)>> {
+        ensure!(
+            self.cells[(x, y)].movable(),
+            "Cannot move a non-movable cell"
+        );
+
+        ensure!(
+            x > 0 && x < self.cells.width() - 1 && y > 0 && y < self.cells.height() - 1,
+            "Cannot move on the edge"
+        );
+
+        let (x_n, y_n) = step((x, y), mv)?;
+
+        match &self.cells[(x_n, y_n)] {
+            // Simplest case, the cell where we are moving to is empty.
+            WarehouseCell::Empty => {
+                self.cells[(x_n, y_n)] = self.cells[(x, y)].clone();
+                self.cells[(x, y)] = WarehouseCell::Empty;
+                Ok(Some((x_n, y_n)))
+            }
+            // Also simple, bump directly into a wall: no move but Ok.
+            WarehouseCell::Wall => Ok(None),
+            // This cannot be possible since there is only one robot.
+            WarehouseCell::Robot => Err(anyhow!("Cannot move into robot")),
+            // Real problem: move into a box an try to push it...
+            WarehouseCell::Box => {
+                // Get the candidate of where the box(es) may be moved to.
+                let (x_c, y_c) = match mv {
+                    Direction::North => (
+                        Some(x),
+                        (0..y_n)
+                            .map(|j| &self.cells[(x, j)])
+                            .rposition(|c| !c.movable()),
+                    ),
+                    Direction::South => (
+                        Some(x),
+                        (y_n + 1..self.cells.height())
+                            .map(|j| &self.cells[(x, j)])
+                            .position(|c| !c.movable())
+                            .map(|y| y_n + 1 + y),
+                    ),
+                    Direction::East => (
+                        (x_n + 1..self.cells.width())
+                            .map(|i| &self.cells[(i, y)])
+                            .position(|c| !c.movable())
+                            .map(|x| x + x_n + 1),
+                        Some(y),
+                    ),
+                    Direction::West => (
+                        (0..x_n)
+                            .map(|i| &self.cells[(i, y)])
+                            .rposition(|c| !c.movable()),
+                        Some(y),
+                    ),
+                };
+                match (x_c, y_c) {
+                    (None, _) | (_, None) => Ok(None),
+                    (Some(x_c), Some(y_c)) => {
+                        // Candidate cell was a wall, cannot move.
+                        if self.cells[(x_c, y_c)] == WarehouseCell::Wall {
+                            return Ok(None);
+                        }
+                        // Otherwise, make the move.
+                        self.cells[(x_c, y_c)] = WarehouseCell::Box;
+                        self.cells[(x_n, y_n)] = self.cells[(x, y)].clone();
+                        self.cells[(x, y)] = WarehouseCell::Empty;
+                        Ok(Some((x_n, y_n)))
+                    }
+                }
+            }
+        }
+    }
+
+    fn calculate_score(&self) -> usize {
+        self.cells
+            .iter()
+            .filter(|(_, cell)| **cell == WarehouseCell::Box)
+            .map(|((i, j), _)| 100 * j + i)
+            .sum()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct BigWarehouse {
+    cells: Grid<BigWarehouseCell>,
+    robot_location: (usize, usize),
+}
+
+impl FromStr for BigWarehouse {
+    type Err = anyhow::Error;
+
+    fn from_str(input_str: &str) -> Result<Self> {
+        let mut robot_location: Option<(usize, usize)> = None;
+        let cells = Grid::parse_with(input_str, |position, c| {
+            let c = BigWarehouseCell::from_char(c)?;
+            if let BigWarehouseCell::Robot = c {
+                ensure!(robot_location.is_none(), "Second robot");
+                robot_location = Some(position);
+            }
+            Ok(c)
+        })?;
+        ensure!(cells.width() % 2 == 0, "Width must be even");
+        check_walls(input_str, 2)?;
+
+        if let Some(((x, y), _)) = cells.iter().find(|&((x, y), cell)| match cell {
+            BigWarehouseCell::LeftBox => cells.get((x + 1, y)) != Some(&BigWarehouseCell::RightBox),
+            BigWarehouseCell::RightBox => {
+                x == 0 || cells.get((x - 1, y)) != Some(&BigWarehouseCell::LeftBox)
+            }
+            _ => false,
+        }) {
+            let line = input_str.lines().nth(y).unwrap_or_default();
+            return Err(PuzzleError::malformed(y, x, line, "Boxes must be in pairs").into());
+        }
+
+        let robot_location =
+            robot_location.ok_or_else(|| PuzzleError::unsolvable("Robot not found"))?;
+
+        Ok(Self {
+            cells,
+            robot_location,
+        })
+    }
+}
+
+/// Checks that the warehouse is surrounded by walls: a full row at the top and bottom, and
+/// `thickness` columns on the left and right.
+fn check_walls(input_str: &str, thickness: usize) -> PuzzleResult<()> {
+    let height = input_str.lines().count();
+    for (y, line) in input_str.lines().enumerate() {
+        let width = line.chars().count();
+        let edge_row = y == 0 || y + 1 == height;
+        let gap = line
+            .char_indices()
+            .enumerate()
+            .find(|&(x, (_, c))| c != '#' && (edge_row || x < thickness || x + thickness >= width));
+        if let Some((_, (offset, _))) = gap {
+            return Err(PuzzleError::malformed(
+                y,
+                offset,
+                line,
+                "The warehouse must be surrounded by walls",
+            ));
+        }
+    }
+    Ok(())
+}
+
+impl BigWarehouse {
+    fn from_regular_warehouse_str(input_str: &str) -> Result<Self> {
+        let normal_warehouse = Warehouse::from_str(input_str)?;
+
+        let cells: Vec<Vec<BigWarehouseCell>> = normal_warehouse
+            .cells
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .flat_map(|cell| match cell {
+                        WarehouseCell::Empty => [BigWarehouseCell::Empty, BigWarehouseCell::Empty],
+                        WarehouseCell::Wall => [BigWarehouseCell::Wall, BigWarehouseCell::Wall],
+                        WarehouseCell::Box => {
+                            [BigWarehouseCell::LeftBox, BigWarehouseCell::RightBox]
+                        }
+                        WarehouseCell::Robot => [BigWarehouseCell::Robot, BigWarehouseCell::Empty],
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self {
+            cells: Grid::from_rows(cells)?,
+            robot_location: (
+                normal_warehouse.robot_location.0 * 2,
+                normal_warehouse.robot_location.1,
+            ),
+        })
+    }
+
+    fn get(&self, x: usize, y: usize) -> &BigWarehouseCell {
+        &self.cells[(x, y)]
+    }
+
+    fn can_move_cell(&self, mv: &Direction, (x, y): (usize, usize)) -> Result<bool> {
+        ensure!(self.get(x, y).movable(), "Cannot move a non-movable cell");
+
+        ensure!(
+            x > 1 && x < self.cells.width() - 2 && y > 0 && y < self.cells.height() - 1,
+            "Cannot move on the edge"
+        );
+
+        let (x_n, y_n) = step((x, y), mv)?;
+
+        match self.get(x_n, y_n) {
+            BigWarehouseCell::Empty => Ok(true),
+            BigWarehouseCell::Wall => Ok(false),
+            BigWarehouseCell::Robot => Err(anyhow!("Cannot move into robot")),
+            BigWarehouseCell::LeftBox => match mv {
+                Direction::East | Direction::West => self.can_move_cell(mv, (x_n, y_n)),
+                Direction::North | Direction::South => {
+                    ensure!(
+                        *self.get(x_n + 1, y_n) == BigWarehouseCell::RightBox,
+                        "Missing paired right box"
+                    );
+                    let can_move_left = self.can_move_cell(mv, (x_n, y_n))?;
+                    let can_move_right = self.can_move_cell(mv, (x_n + 1, y_n))?;
+                    Ok(can_move_left & can_move_right)
+                }
+            },
+            BigWarehouseCell::RightBox => match mv {
+                Direction::East | Direction::West => self.can_move_cell(mv, (x_n, y_n)),
+                Direction::North | Direction::South => {
+                    ensure!(
+                        *self.get(x_n - 1, y_n) == BigWarehouseCell::LeftBox,
+                        "Missing paired left box"
+                    );
+                    let can_move_right = self.can_move_cell(mv, (x_n, y_n))?;
+                    let can_move_left = self.can_move_cell(mv, (x_n - 1, y_n))?;
+                    Ok(can_move_left & can_move_right)
+                }
+            },
+        }
+    }
+
+    fn move_cell(
+        &mut self,
+        mv: &Direction,
+        (x, y): (usize, usize),
+    ) -> Result<Option<(usize, usize)>> {
+        if !self.can_move_cell(mv, (x, y))? {
+            return Ok(None);
+        }
+
+        let (x_n, y_n) = step((x, y), mv)?;
+
+        // We know that we can move into the new cell.
+        // However, we may need to cascade other movements if the
+        // neighboring cells is a box.
+        match self.get(x_n, y_n) {
+            BigWarehouseCell::Wall => unreachable!(),
+            BigWarehouseCell::Robot => unreachable!(),
+            BigWarehouseCell::Empty => {
+                // No need to recurse, just a simple swap moving into an empty cell.
+            }
+            BigWarehouseCell::LeftBox => {
+                match mv {
+                    Direction::East | Direction::West => {
+                        // We know these boxes can be moved, so they must be moved before the current cell.
+                        self.move_cell(mv, (x_n, y_n))?;
+                    }
+                    Direction::North | Direction::South => {
+                        self.move_cell(mv, (x_n, y_n))?;
+                        self.move_cell(mv, (x_n + 1, y_n))?;
+                    }
+                }
+            }
+            BigWarehouseCell::RightBox => {
+                match mv {
+                    Direction::East | Direction::West => {
+                        // We know these boxes can be moved, so they must be moved before the current cell.
+                        self.move_cell(mv, (x_n, y_n))?;
+                    }
+                    Direction::North | Direction::South => {
+                        self.move_cell(mv, (x_n, y_n))?;
+                        self.move_cell(mv, (x_n - 1, y_n))?;
+                    }
+                }
+            }
+        };
+        // Now do the actual move.
+        self.cells[(x_n, y_n)] = self.cells[(x, y)].clone();
+        self.cells[(x, y)] = BigWarehouseCell::Empty;
+        Ok(Some((x_n, y_n)))
+    }
+
+    fn calculate_score(&self) -> usize {
+        self.cells
+            .iter()
+            .filter(|(_, cell)| **cell == BigWarehouseCell::LeftBox)
+            .map(|((i, j), _)| 100 * j + i)
+            .sum()
+    }
+}
+
+/// The cell next to `(x, y)` in the direction `mv`.
+fn step((x, y): (usize, usize), mv: &Direction) -> Result<(usize, usize)> {
+    Point::new(x, y)
+        .checked_add(mv.vector())
+        .map(Into::into)
+        .ok_or_else(|| anyhow!("Cannot move out of the warehouse"))
+}
+
+fn split_sections(input_str: &str) -> PuzzleResult<(&str, &str)> {
+    input_str.split_once("\n\n").ok_or_else(|| {
+        PuzzleError::malformed_at(
+            input_str,
+            input_str.len(),
+            "Expected the warehouse and the moves separated by a blank line",
+        )
+    })
+}
+
+fn parse_moves(input_str: &str, moves_str: &str) -> PuzzleResult<Vec<Direction>> {
+    moves_str
+        .char_indices()
+        .filter(|(_, c)| *c != '\n')
+        .map(|(offset, c)| {
+            Direction::from_arrow(c).ok_or_else(|| {
+                PuzzleError::malformed_slice(
+                    input_str,
+                    &moves_str[offset..],
+                    format!("Invalid move character: {}", c),
+                )
+            })
+        })
+        .collect()
+}
+
+fn preprocessing(input_str: &str) -> Result<(Warehouse, Vec<Direction>)> {
+    let (warehouse_str, moves_str) = split_sections(input_str)?;
+    let warehouse = Warehouse::from_str(warehouse_str)?;
+    let moves = parse_moves(input_str, moves_str)?;
+    Ok((warehouse, moves))
+}
+
+fn preprocessing_big(input_str: &str) -> Result<(BigWarehouse, Vec<Direction>)> {
+    let (warehouse_str, moves_str) = split_sections(input_str)?;
+    let warehouse = BigWarehouse::from_regular_warehouse_str(warehouse_str)?;
+    let moves = parse_moves(input_str, moves_str)?;
+    Ok((warehouse, moves))
+}
+
+impl Display for WarehouseCell {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", char::from(self.clone()))
+    }
+}
+
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+impl Display for BigWarehouseCell {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", char::from(self.clone()))
+    }
+}
+
+impl Display for BigWarehouse {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[fixture]
+    fn sample_input_str_simple() -> &'static str {
+        "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv
+<v>>v<<"
+    }
+
+    #[fixture]
+    fn sample_input_str_big_simple() -> &'static str {
+        "#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^"
+    }
+
+    #[fixture]
+    fn sample_big_warehouse_str() -> &'static str {
+        "##############
+##......##..##
+##..........##
+##....[][]@.##
+##....[]....##
+##..........##
+##############"
+    }
+
+    #[fixture]
+    fn sample_input_str() -> &'static str {
+        "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^"
+    }
+
+    #[fixture]
+    fn expected_moves_simple() -> Vec<Direction> {
+        vec![
+            Direction::West,
+            Direction::North,
+            Direction::North,
+            Direction::East,
+            Direction::East,
+            Direction::East,
+            Direction::South,
+            Direction::South,
+            Direction::West,
+            Direction::South,
+            Direction::East,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+            Direction::West,
+        ]
+    }
+
+    #[fixture]
+    fn expected_position_simple() -> (usize, usize) {
+        (4, 4)
+    }
+
+    #[fixture]
+    fn expected_warehouse() -> Warehouse {
+        Warehouse {
+            cells: Grid::from_rows(vec![
+                vec![WarehouseCell::Wall; 8],
+                vec![
+                    WarehouseCell::Wall,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Box,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Box,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Wall,
+                ],
+                vec![
+                    WarehouseCell::Wall,
+                    WarehouseCell::Wall,
+                    WarehouseCell::Robot,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Box,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Wall,
+                ],
+                vec![
+                    WarehouseCell::Wall,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Box,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Wall,
+                ],
+                vec![
+                    WarehouseCell::Wall,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Wall,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Box,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Wall,
+                ],
+                vec![
+                    WarehouseCell::Wall,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Box,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Wall,
+                ],
+                vec![
+                    WarehouseCell::Wall,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Empty,
+                    WarehouseCell::Wall,
+                ],
+                vec![WarehouseCell::Wall; 8],
+            ])
+            .unwrap(),
+            robot_location: (2, 2),
+        }
+    }
+
+    #[rstest]
+    fn test_preprocessing(
+        sample_input_str_simple: &str,
+        expected_warehouse: Warehouse,
+        expected_moves_simple: Vec<Direction>,
+    ) {
+        let (warehouse, moves) = preprocessing(sample_input_str_simple).unwrap();
+        assert_eq!(warehouse, expected_warehouse);
+        assert_eq!(moves, expected_moves_simple);
+    }
+
+    #[rstest]
+    fn test_processing_big(sample_input_str_big_simple: &str, sample_big_warehouse_str: &str) {
+        let (big_warehouse, _) = preprocessing_big(sample_input_str_big_simple).unwrap();
+        assert_eq!(
+            big_warehouse,
+            BigWarehouse::from_str(sample_big_warehouse_str).unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_big_score() {
+        let big_warehouse = BigWarehouse::from_str(
+            "####################
+##[].......[].[][]##
+##[]...........[].##
+##[]........[][][]##
+##[]......[]....[]##
+##..##......[]....##
+##..[]............##
+##..@......[].[][]##
+##......[][]..[]..##
+####################",
+        )
+        .unwrap();
+        assert_eq!(big_warehouse.calculate_score(), 9021);
+    }
+
+    #[rstest]
+    fn test_simple_moves(
+        expected_warehouse: Warehouse,
+        expected_moves_simple: Vec<Direction>,
+        expected_position_simple: (usize, usize),
+    ) {
+        let mut warehouse = expected_warehouse.clone();
+        println!("Initial configuration:\n{}", warehouse);
+        for (k, mv) in expected_moves_simple.iter().enumerate() {
+            if let Some((x, y)) = warehouse.move_cell(mv, warehouse.robot_location).unwrap() {
+                warehouse.robot_location = (x, y);
+            }
+            println!("Configuration after move {} {}:\n{}", k + 1, mv, warehouse);
+        }
+        assert_eq!(warehouse.robot_location, expected_position_simple);
+    }
+
+    #[rstest]
+    fn test_exercise_1(sample_input_str_simple: &str, sample_input_str: &str) {
+        assert_eq!(exercise_1(sample_input_str_simple).unwrap(), 2028,);
+
+        assert_eq!(exercise_1(sample_input_str).unwrap(), 10092,);
+    }
+}
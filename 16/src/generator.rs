@@ -0,0 +1,64 @@
+//! Random mazes.
+
+use grid::Grid;
+use rand::{rngs::StdRng, seq::IndexedRandom, Rng, SeedableRng};
+
+/// A square maze of side `size`, rounded up to an odd number of at least 5, carved by a random
+/// depth-first walk. A tenth of the inner walls are then knocked down so that there are loops and
+/// several best paths. The start is in the bottom left corner, the end in the top right one.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let side = size.max(5) | 1;
+    let mut maze = Grid::new(side, side, '#');
+
+    let start = (1, side - 2);
+    maze[start] = '.';
+    let mut stack = vec![start];
+    while let Some(&(x, y)) = stack.last() {
+        let unvisited: Vec<(usize, usize)> = [(0, 2), (2, 0), (0, -2), (-2, 0)]
+            .iter()
+            .filter_map(|&(dx, dy)| {
+                let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+                (next.0 < side - 1 && next.1 < side - 1 && maze[next] == '#').then_some(next)
+            })
+            .collect();
+        match unvisited.choose(&mut rng) {
+            Some(&next) => {
+                maze[((x + next.0) / 2, (y + next.1) / 2)] = '.';
+                maze[next] = '.';
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    for y in 1..side - 1 {
+        for x in 1..side - 1 {
+            if maze[(x, y)] == '#' && rng.random_bool(0.1) {
+                maze[(x, y)] = '.';
+            }
+        }
+    }
+    maze[start] = 'S';
+    maze[(side - 2, 1)] = 'E';
+    maze.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day16;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(41, 16);
+        assert_eq!(input, generate(41, 16));
+        let score: usize = Day16.part_1(&input).unwrap().parse().unwrap();
+        let tiles: usize = Day16.part_2(&input).unwrap().parse().unwrap();
+        assert!(score > 0 && tiles > 0);
+        assert_eq!(generate(0, 16).lines().count(), 5);
+    }
+}
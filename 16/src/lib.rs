@@ -0,0 +1,354 @@
+pub mod generator;
+
+use anyhow::{anyhow, ensure, Result};
+use aoc_common::{PuzzleError, Solution};
+use generic_search::{astar, astar_dag, Node};
+use grid::{Direction, FromChar, Grid, Point};
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn title(&self) -> &'static str {
+        "Reindeer Maze"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(Maze::from_str(input).map(|_| ()))
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(exercise_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(exercise_2(input)?.to_string())
+    }
+}
+
+pub fn exercise_1(input_str: &str) -> Result<usize> {
+    let maze = Maze::from_str(input_str)?;
+    maze.astar_path_cost()
+}
+
+pub fn exercise_2(input_str: &str) -> Result<usize> {
+    let maze = Maze::from_str(input_str)?;
+    maze.astar_paths_cells()
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Cell {
+    Wall,
+    Empty,
+    Start,
+    End,
+}
+
+impl FromChar for Cell {
+    fn from_char(c: char) -> Result<Self> {
+        match c {
+            '#' => Ok(Cell::Wall),
+            '.' => Ok(Cell::Empty),
+            'S' => Ok(Cell::Start),
+            'E' => Ok(Cell::End),
+            _ => Err(anyhow!("Invalid cell character: {}", c)),
+        }
+    }
+}
+
+impl Cell {
+    fn is_passable(&self) -> bool {
+        !matches!(self, Self::Wall)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Maze {
+    cells: Grid<Cell>,
+    start: (usize, usize),
+    goal: (usize, usize),
+}
+
+impl FromStr for Maze {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut start = None;
+        let mut goal = None;
+        let cells = Grid::parse_with(s, |(x, y), c| {
+            let cell = Cell::from_char(c)?;
+            match cell {
+                Cell::Start => {
+                    ensure!(start.is_none(), "Multiple start cells");
+                    start = Some((x, y));
+                }
+                Cell::End => {
+                    ensure!(goal.is_none(), "Multiple goal cells");
+                    goal = Some((x, y));
+                }
+                _ => {}
+            }
+            Ok(cell)
+        })?;
+
+        Ok(Maze {
+            cells,
+            start: start.ok_or_else(|| PuzzleError::unsolvable("No start cell"))?,
+            goal: goal.ok_or_else(|| PuzzleError::unsolvable("No goal cell"))?,
+        })
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Cell::Wall => '#',
+            Cell::Empty => '.',
+            Cell::Start => 'S',
+            Cell::End => 'E',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+impl Display for Maze {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, std::hash::Hash)]
+struct State(Direction, (usize, usize));
+
+impl State {
+    fn x(&self) -> usize {
+        self.1 .0
+    }
+
+    fn y(&self) -> usize {
+        self.1 .1
+    }
+
+    fn orientation(&self) -> Direction {
+        self.0
+    }
+
+    fn movement_cost(&self, other: &Self) -> Result<usize> {
+        if usize::abs_diff(self.x(), other.x()) > 1 || usize::abs_diff(self.y(), other.y()) > 1 {
+            return Err(anyhow!("Impossible to reach states"));
+        }
+
+        if self.orientation().reverse() == other.orientation() {
+            return Err(anyhow!("Cannot flip orientation"));
+        }
+
+        // Either moved straight forward, or must have rotated 90 degrees _and then moved_ forward.
+        Ok(1 + 1_000 * self.orientation().quarter_turns_to(other.orientation()))
+    }
+
+    fn manhattan_heuristic(&self, goal: (usize, usize)) -> usize {
+        Point::from(self.1).manhattan_distance(goal.into())
+    }
+}
+
+impl Maze {
+    fn astar_path_cost(&self) -> Result<usize> {
+        // Define closures.
+        let successors = |state: &State| self.successors(state);
+        let goal_test = |state: &State| self.goal_test(state.x(), state.y());
+        let heuristic = |state: &State| state.manhattan_heuristic(self.goal);
+        let cost = |parent: &Node<State>, child: &State| {
+            parent.get_cost() + parent.get_state().movement_cost(child).unwrap()
+        };
+
+        if let Some(node) = astar(
+            State(Direction::East, self.start),
+            goal_test,
+            successors,
+            heuristic,
+            cost,
+        ) {
+            // let path = node.node_to_path();
+            // println!("Path: {:?}", path);
+            return Ok(node.get_cost());
+        }
+
+        Err(PuzzleError::unsolvable("No path found").into())
+    }
+
+    fn astar_paths_cells(&self) -> Result<usize> {
+        // Define closures.
+        let successors = |state: &State| self.successors(state);
+        let goal_test = |state: &State| self.goal_test(state.x(), state.y());
+        let heuristic = |state: &State| state.manhattan_heuristic(self.goal);
+        let cost = |parent: &Node<State>, child: &State| {
+            parent.get_cost() + parent.get_state().movement_cost(child).unwrap()
+        };
+
+        if let Some(dag) = astar_dag(
+            State(Direction::East, self.start),
+            goal_test,
+            successors,
+            heuristic,
+            cost,
+        ) {
+            let cells_set: HashSet<(usize, usize)> =
+                dag.states().iter().map(|s| (s.x(), s.y())).collect();
+            return Ok(cells_set.len());
+        }
+
+        Err(PuzzleError::unsolvable("No path found").into())
+    }
+
+    fn goal_test(&self, x: usize, y: usize) -> bool {
+        (x, y) == self.goal
+    }
+
+    fn successors(&self, state: &State) -> Vec<State> {
+        // The reindeer can go anywhere but back where it came from.
+        Direction::ALL
+            .into_iter()
+            .filter(|&direction| direction != state.orientation().reverse())
+            .filter_map(|direction| {
+                let next = self.cells.translate(state.1.into(), direction.vector())?;
+                self.cells[next]
+                    .is_passable()
+                    .then(|| State(direction, next.into()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use generic_search::{astar_paths_with_stats, astar_with_stats, SearchStats};
+    use rstest::*;
+    use std::cell::RefCell;
+
+    const EXAMPLE_1: &str = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+    const EXAMPLE_2: &str = "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+    #[rstest]
+    #[case(EXAMPLE_1, 7036)]
+    #[case(EXAMPLE_2, 11048)]
+    fn test_exercise_1(#[case] input: &str, #[case] expected: usize) {
+        let result = exercise_1(input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(EXAMPLE_1, 45)]
+    #[case(EXAMPLE_2, 64)]
+    fn test_exercise_2(#[case] input: &str, #[case] expected: usize) {
+        let result = exercise_2(input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    fn astar_stats(maze: &Maze, use_heuristic: bool) -> (usize, SearchStats) {
+        let (node, stats) = astar_with_stats(
+            State(Direction::East, maze.start),
+            |state| maze.goal_test(state.x(), state.y()),
+            |state| maze.successors(state),
+            |state| {
+                if use_heuristic {
+                    state.manhattan_heuristic(maze.goal)
+                } else {
+                    0
+                }
+            },
+            |parent, child| parent.get_cost() + parent.get_state().movement_cost(child).unwrap(),
+        );
+        (node.unwrap().get_cost(), stats)
+    }
+
+    #[rstest]
+    #[case(EXAMPLE_1)]
+    #[case(EXAMPLE_2)]
+    fn test_manhattan_heuristic_expands_fewer_nodes(#[case] input: &str) {
+        let maze = Maze::from_str(input).unwrap();
+        let (uninformed_cost, uninformed) = astar_stats(&maze, false);
+        let (informed_cost, informed) = astar_stats(&maze, true);
+        assert_eq!(uninformed_cost, informed_cost);
+        assert!(informed.expanded <= uninformed.expanded);
+    }
+
+    #[rstest]
+    #[case(EXAMPLE_1, 7036)]
+    #[case(EXAMPLE_2, 11048)]
+    fn test_astar_never_expands_a_state_twice(#[case] input: &str, #[case] expected: usize) {
+        let maze = Maze::from_str(input).unwrap();
+        let expanded = RefCell::new(vec![]);
+        let (node, stats) = astar_with_stats(
+            State(Direction::East, maze.start),
+            |state| maze.goal_test(state.x(), state.y()),
+            |state| {
+                expanded.borrow_mut().push(state.clone());
+                maze.successors(state)
+            },
+            |state| state.manhattan_heuristic(maze.goal),
+            |parent, child| parent.get_cost() + parent.get_state().movement_cost(child).unwrap(),
+        );
+        assert_eq!(node.unwrap().get_cost(), expected);
+
+        let expanded = expanded.into_inner();
+        let distinct: HashSet<&State> = expanded.iter().collect();
+        assert_eq!(distinct.len(), expanded.len());
+        assert_eq!(stats.expanded, expanded.len());
+    }
+
+    #[rstest]
+    fn test_astar_paths_skips_superseded_entries() {
+        let maze = Maze::from_str(EXAMPLE_2).unwrap();
+        let (nodes, stats) = astar_paths_with_stats(
+            State(Direction::East, maze.start),
+            |state| maze.goal_test(state.x(), state.y()),
+            |state| maze.successors(state),
+            |state| state.manhattan_heuristic(maze.goal),
+            |parent, child| parent.get_cost() + parent.get_state().movement_cost(child).unwrap(),
+        );
+        let min_cost = nodes.unwrap().iter().map(|n| n.get_cost()).min().unwrap();
+        assert_eq!(min_cost, 11048);
+        // Each of these would otherwise have been expanded again, along with its descendants.
+        assert!(stats.duplicates > 0);
+    }
+}
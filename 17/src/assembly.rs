@@ -0,0 +1,276 @@
+//! A readable form of the 3-bit programs, one instruction per line:
+//!
+//! ```text
+//! loop:
+//!     bst a    ; b = a % 8
+//!     adv 3    ; a = a >> 3
+//!     out b    ; print b % 8
+//!     jnz loop ; if a != 0 goto loop
+//! ```
+//!
+//! Combo operands are written `0` to `3` or as the registers `a`, `b` and `c`, literal operands as
+//! `0` to `7`, and `jnz` may jump to a `label:` instead, named with letters, digits and `_` but not
+//! a number. `bxc` ignores its operand, which can be left out when it is 0. Everything after a `;`
+//! is a comment.
+
+use crate::{tape_error, Instruction, Operand};
+use aoc_common::{PuzzleError, PuzzleResult};
+use std::collections::HashMap;
+
+const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+/// Turns a tape into the source [`assemble`] reads back, labelling the jump targets and
+/// commenting what each instruction does.
+pub fn disassemble(tape: &[u8]) -> PuzzleResult<String> {
+    if tape.len() % 2 == 1 {
+        return Err(tape_error(
+            tape,
+            tape.len(),
+            "Expected an operand after the last opcode",
+        ));
+    }
+    let is_label = |target: u8| target.is_multiple_of(2) && (target as usize) < tape.len();
+    let targets: Vec<usize> = tape
+        .chunks_exact(2)
+        .filter(|pair| pair[0] == 3 && is_label(pair[1]))
+        .map(|pair| pair[1] as usize)
+        .collect();
+
+    let mut source = String::new();
+    for (i, pair) in tape.chunks_exact(2).enumerate() {
+        if targets.contains(&(2 * i)) {
+            source += &format!("l{}:\n", 2 * i);
+        }
+        let label = pair[0] == 3 && is_label(pair[1]);
+        source += &format!("    {}\n", instruction_line(tape, 2 * i, label)?);
+    }
+    Ok(source)
+}
+
+/// The instruction at `position` of the tape followed by a comment saying what it does, writing
+/// the target of `jnz` as a label when `label` is set.
+pub(crate) fn instruction_line(tape: &[u8], position: usize, label: bool) -> PuzzleResult<String> {
+    let instruction = Instruction::decode(tape, position)?;
+    let pair = &tape[position..position + 2];
+    let operand = match (&instruction, instruction.extract_operand()) {
+        (Instruction::Bxc, _) if pair[1] == 0 => String::new(),
+        (Instruction::Bxc, _) => pair[1].to_string(),
+        (Instruction::Jnz(target), _) if label => format!("l{}", target),
+        (_, Operand::Literal(value)) => value.to_string(),
+        (_, Operand::Combo(value @ 0..=3)) => value.to_string(),
+        (_, Operand::Combo(value @ 4..=6)) => ["a", "b", "c"][value as usize - 4].to_string(),
+        (_, Operand::Combo(_)) => {
+            return Err(tape_error(tape, position + 1, "Reserved combo operand 7"))
+        }
+    };
+    let code = format!("{} {}", MNEMONICS[pair[0] as usize], operand);
+    Ok(format!(
+        "{:<9}; {}",
+        code.trim_end(),
+        comment(&instruction, &operand)
+    ))
+}
+
+fn comment(instruction: &Instruction, operand: &str) -> String {
+    match instruction {
+        Instruction::Adv(_) => format!("a = a >> {}", operand),
+        Instruction::Bxl(_) => format!("b = b ^ {}", operand),
+        Instruction::Bst(_) => format!("b = {} % 8", operand),
+        Instruction::Jnz(_) => format!("if a != 0 goto {}", operand),
+        Instruction::Bxc => "b = b ^ c".to_string(),
+        Instruction::Out(_) => format!("print {} % 8", operand),
+        Instruction::Bdv(_) => format!("b = a >> {}", operand),
+        Instruction::Cdv(_) => format!("c = a >> {}", operand),
+    }
+}
+
+/// Reads the source written by [`disassemble`] back into a tape.
+pub fn assemble(source: &str) -> PuzzleResult<Vec<u8>> {
+    let lines: Vec<(usize, &str)> = source
+        .lines()
+        .map(|line| line.split(';').next().unwrap_or_default().trim_end())
+        .enumerate()
+        .filter(|(_, code)| !code.trim().is_empty())
+        .collect();
+
+    // Labels are collected first so that jumps can go forwards.
+    let mut labels = HashMap::new();
+    let mut address = 0;
+    for &(index, code) in &lines {
+        let Some(label) = code.trim().strip_suffix(':') else {
+            address += 2;
+            continue;
+        };
+        if label.is_empty() || !label.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(PuzzleError::malformed_token(
+                index,
+                source_line(source, index),
+                code.trim(),
+                "Invalid label",
+            ));
+        }
+        if label.chars().all(|c| c.is_ascii_digit()) {
+            return Err(PuzzleError::malformed_token(
+                index,
+                source_line(source, index),
+                label,
+                "Labels cannot be numbers",
+            ));
+        }
+        if labels.insert(label, address).is_some() {
+            return Err(PuzzleError::malformed_token(
+                index,
+                source_line(source, index),
+                label,
+                "Duplicate label",
+            ));
+        }
+    }
+
+    let mut tape = vec![];
+    for &(index, code) in &lines {
+        let line = source_line(source, index);
+        let error =
+            |token: &str, message: &str| PuzzleError::malformed_token(index, line, token, message);
+        if code.trim().ends_with(':') {
+            continue;
+        }
+        let mut tokens = code.split_whitespace();
+        let mnemonic = tokens.next().unwrap_or_default();
+        let opcode = MNEMONICS
+            .iter()
+            .position(|&m| m == mnemonic)
+            .ok_or_else(|| error(mnemonic, "Unknown mnemonic"))? as u8;
+        let operand = match (opcode, tokens.next()) {
+            (4, None) => 0,
+            (_, None) => {
+                return Err(PuzzleError::malformed(
+                    index,
+                    code.len(),
+                    line,
+                    "Expected an operand",
+                ))
+            }
+            (3, Some(token)) if labels.contains_key(token) => {
+                let target = labels[token];
+                if target > 7 {
+                    return Err(error(token, "The label is too far for a 3-bit jump"));
+                }
+                target
+            }
+            (_, Some(token)) => {
+                let combo = matches!(
+                    Instruction::new(opcode, 0).map(|instruction| instruction.extract_operand()),
+                    Some(Operand::Combo(_))
+                );
+                match token {
+                    "0" | "1" | "2" | "3" => token.parse().unwrap(),
+                    "4" | "5" | "6" | "7" if !combo => token.parse().unwrap(),
+                    "a" | "b" | "c" if combo => token.as_bytes()[0] - b'a' + 4,
+                    _ if combo => return Err(error(token, "Expected 0 to 3, a, b or c")),
+                    _ if opcode == 3 => return Err(error(token, "Expected 0 to 7 or a label")),
+                    _ => return Err(error(token, "Expected 0 to 7")),
+                }
+            }
+        };
+        if let Some(token) = tokens.next() {
+            return Err(error(token, "Unexpected trailing characters"));
+        }
+        tape.extend([opcode, operand]);
+    }
+    Ok(tape)
+}
+
+fn source_line(source: &str, index: usize) -> &str {
+    source.lines().nth(index).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[test]
+    fn test_round_trip() {
+        for opcode in 0..8 {
+            for operand in 0..8 {
+                let instruction = Instruction::new(opcode, operand).unwrap();
+                let source = disassemble(&[opcode, operand]);
+                if matches!(instruction.extract_operand(), Operand::Combo(7)) {
+                    assert!(source.is_err());
+                } else {
+                    assert_eq!(assemble(&source.unwrap()).unwrap(), [opcode, operand]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let tape = [2, 4, 1, 1, 7, 5, 4, 6, 0, 3, 1, 4, 5, 5, 3, 0];
+        let source = disassemble(&tape).unwrap();
+        assert_eq!(
+            source,
+            "l0:
+    bst a    ; b = a % 8
+    bxl 1    ; b = b ^ 1
+    cdv b    ; c = a >> b
+    bxc 6    ; b = b ^ c
+    adv 3    ; a = a >> 3
+    bxl 4    ; b = b ^ 4
+    out b    ; print b % 8
+    jnz l0   ; if a != 0 goto l0
+"
+        );
+        assert_eq!(assemble(&source).unwrap(), tape);
+    }
+
+    #[rstest]
+    #[case(
+        &[0, 3, 5],
+        "Line 1, column 6: Expected an operand after the last opcode\n1 | 0,3,5\n  |      ^"
+    )]
+    #[case(
+        &[0, 3, 5, 7],
+        "Line 1, column 7: Reserved combo operand 7\n1 | 0,3,5,7\n  |       ^"
+    )]
+    fn test_disassemble_errors(#[case] tape: &[u8], #[case] expected: &str) {
+        assert_eq!(disassemble(tape).err().unwrap().to_string(), expected);
+    }
+
+    #[test]
+    fn test_assemble_labels() {
+        let source = "  jnz end\nloop: ; comment\n out a\nend:\n\tjnz loop\n";
+        assert_eq!(assemble(source).unwrap(), [3, 4, 5, 4, 3, 2]);
+    }
+
+    #[rstest]
+    #[case("mul 3", "Line 1, column 1: Unknown mnemonic\n1 | mul 3\n  | ^^^")]
+    #[case(
+        "bst 7",
+        "Line 1, column 5: Expected 0 to 3, a, b or c\n1 | bst 7\n  |     ^"
+    )]
+    #[case("bxl a", "Line 1, column 5: Expected 0 to 7\n1 | bxl a\n  |     ^")]
+    #[case(
+        "out a\njnz end",
+        "Line 2, column 5: Expected 0 to 7 or a label\n2 | jnz end\n  |     ^^^"
+    )]
+    #[case("adv\n", "Line 1, column 4: Expected an operand\n1 | adv\n  |    ^")]
+    #[case(
+        "adv 3 b",
+        "Line 1, column 7: Unexpected trailing characters\n1 | adv 3 b\n  |       ^"
+    )]
+    #[case("x:\nx:", "Line 2, column 1: Duplicate label\n2 | x:\n  | ^")]
+    #[case("a b:", "Line 1, column 1: Invalid label\n1 | a b:\n  | ^^^^")]
+    #[case(
+        "out a\n2:\njnz 2",
+        "Line 2, column 1: Labels cannot be numbers\n2 | 2:\n  | ^"
+    )]
+    #[case(
+        "out a\nout a\nout a\nout a\nend:\njnz end",
+        "Line 6, column 5: The label is too far for a 3-bit jump\n6 | jnz end\n  |     ^^^"
+    )]
+    fn test_assemble_errors(#[case] source: &str, #[case] expected: &str) {
+        assert_eq!(assemble(source).err().unwrap().to_string(), expected);
+    }
+}
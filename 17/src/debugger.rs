@@ -0,0 +1,309 @@
+//! A step debugger for the [`Computer`], reading one command per line so that sessions can also be
+//! scripted.
+
+use crate::{assembly, Computer, MAX_STEPS};
+use anyhow::{bail, Result};
+use std::collections::{BTreeSet, VecDeque};
+use std::io::{BufRead, Write};
+
+/// Instructions kept for `trace`, the oldest being dropped first.
+const TRACE_LENGTH: usize = 1000;
+
+const REGISTERS: [&str; 3] = ["a", "b", "c"];
+
+const HELP: &str = "\
+step [n]            run n instructions (1 by default)
+continue            run until a breakpoint, a watchpoint, an error or the end
+break [ip]          stop before the instruction at ip, or list the breakpoints
+delete <ip>         remove the breakpoint at ip
+watch <a|b|c>       stop when the register changes
+unwatch <a|b|c>     stop watching the register
+registers           print the registers, the instruction pointer and the output
+set <a|b|c|ip> <n>  change a register or the instruction pointer
+trace [n]           print the last n instructions run (10 by default)
+list                print the program, marking the next instruction
+quit";
+
+/// An instruction that ran, with the registers it left.
+struct Step {
+    instruction_pointer: usize,
+    line: String,
+    registers: [usize; 3],
+}
+
+struct Debugger {
+    computer: Computer,
+    breakpoints: BTreeSet<usize>,
+    watchpoints: [bool; 3],
+    trace: VecDeque<Step>,
+}
+
+/// Debugs the program of `input`, reading commands from `commands` until `quit` or their end.
+pub fn debug(input: &str, commands: impl BufRead, mut out: impl Write) -> Result<()> {
+    let mut debugger = Debugger {
+        computer: Computer::parse(input)?,
+        breakpoints: BTreeSet::new(),
+        watchpoints: [false; 3],
+        trace: VecDeque::new(),
+    };
+    writeln!(out, "Type help for the commands.")?;
+    debugger.print_next(&mut out)?;
+    write!(out, "(dbg) ")?;
+    out.flush()?;
+    for command in commands.lines() {
+        let command = command?;
+        let words: Vec<&str> = command.split_whitespace().collect();
+        if matches!(words.first(), Some(&"quit" | &"q")) {
+            break;
+        }
+        if let Err(error) = debugger.execute(&words, &mut out) {
+            writeln!(out, "Error: {:#}", error)?;
+        }
+        write!(out, "(dbg) ")?;
+        out.flush()?;
+    }
+    writeln!(out)?;
+    Ok(())
+}
+
+impl Debugger {
+    fn execute(&mut self, words: &[&str], out: &mut impl Write) -> Result<()> {
+        match words {
+            [] => {}
+            ["help" | "h"] => writeln!(out, "{}", HELP)?,
+            ["step" | "s"] => self.run(1, true, out)?,
+            ["step" | "s", count] => self.run(count.parse()?, true, out)?,
+            ["continue" | "c"] => self.run(MAX_STEPS, false, out)?,
+            ["break" | "b"] => {
+                let breakpoints: Vec<String> =
+                    self.breakpoints.iter().map(usize::to_string).collect();
+                writeln!(out, "Breakpoints: {}", breakpoints.join(", "))?;
+            }
+            ["break" | "b", ip] => {
+                let ip = ip.parse()?;
+                if ip >= self.computer.tape.len() {
+                    bail!("The program has {} positions", self.computer.tape.len());
+                }
+                self.breakpoints.insert(ip);
+            }
+            ["delete" | "d", ip] => {
+                if !self.breakpoints.remove(&ip.parse()?) {
+                    bail!("No breakpoint at {}", ip);
+                }
+            }
+            ["watch" | "w", register] => self.watchpoints[register_index(register)?] = true,
+            ["unwatch", register] => self.watchpoints[register_index(register)?] = false,
+            ["registers" | "r"] => self.print_registers(out)?,
+            ["set", "ip", value] => self.computer.instruction_pointer = value.parse()?,
+            ["set", register, value] => {
+                let value = value.parse()?;
+                match register_index(register)? {
+                    0 => self.computer.ax = value,
+                    1 => self.computer.bx = value,
+                    _ => self.computer.cx = value,
+                }
+            }
+            ["trace" | "t"] => self.print_trace(10, out)?,
+            ["trace" | "t", count] => self.print_trace(count.parse()?, out)?,
+            ["list" | "l"] => {
+                for i in 0..self.computer.tape.len() / 2 {
+                    let marker = if 2 * i == self.computer.instruction_pointer {
+                        "=>"
+                    } else {
+                        "  "
+                    };
+                    let line = assembly::instruction_line(&self.computer.tape, 2 * i, false)?;
+                    writeln!(out, "{} {:>3}: {}", marker, 2 * i, line)?;
+                }
+            }
+            _ => bail!(
+                "Unknown command {:?}, type help for the commands",
+                words.join(" ")
+            ),
+        }
+        Ok(())
+    }
+
+    /// Runs up to `count` instructions, printing each of them if `verbose`, and stops early on a
+    /// breakpoint (except where it starts), a watchpoint, an error or the end of the program.
+    fn run(&mut self, count: usize, verbose: bool, out: &mut impl Write) -> Result<()> {
+        for i in 0..count {
+            let ip = self.computer.instruction_pointer;
+            if !self.computer.valid_instruction() {
+                writeln!(out, "Halted with output {}", self.computer.print_output())?;
+                return Ok(());
+            }
+            if i > 0 && self.breakpoints.contains(&ip) {
+                writeln!(out, "Breakpoint at {}", ip)?;
+                break;
+            }
+            let before = self.registers();
+            let line = assembly::instruction_line(&self.computer.tape, ip, false)?;
+            let printed = self
+                .computer
+                .next_instruction()
+                .and_then(|instruction| self.computer.execute_instruction(&instruction))?;
+            if verbose {
+                writeln!(out, "{:>3}: {}", ip, line)?;
+            }
+            if let Some(value) = printed {
+                writeln!(out, "Output: {}", value)?;
+            }
+            if self.trace.len() == TRACE_LENGTH {
+                self.trace.pop_front();
+            }
+            self.trace.push_back(Step {
+                instruction_pointer: ip,
+                line,
+                registers: self.registers(),
+            });
+
+            let after = self.registers();
+            let changed: Vec<usize> = (0..3)
+                .filter(|&r| self.watchpoints[r] && before[r] != after[r])
+                .collect();
+            for &r in &changed {
+                writeln!(
+                    out,
+                    "Watchpoint {}: {} -> {}",
+                    REGISTERS[r], before[r], after[r]
+                )?;
+            }
+            if !changed.is_empty() {
+                break;
+            }
+            if i + 1 == count && !verbose {
+                writeln!(out, "Stopped after {} instructions", count)?;
+            }
+        }
+        self.print_next(out)
+    }
+
+    fn registers(&self) -> [usize; 3] {
+        [self.computer.ax, self.computer.bx, self.computer.cx]
+    }
+
+    fn print_next(&self, out: &mut impl Write) -> Result<()> {
+        let ip = self.computer.instruction_pointer;
+        if self.computer.valid_instruction() {
+            let line = assembly::instruction_line(&self.computer.tape, ip, false)?;
+            writeln!(out, "Next {:>3}: {}", ip, line)?;
+        } else {
+            writeln!(out, "Next {:>3}: end of the program", ip)?;
+        }
+        Ok(())
+    }
+
+    fn print_registers(&self, out: &mut impl Write) -> Result<()> {
+        let [a, b, c] = self.registers();
+        writeln!(out, "a = {}\nb = {}\nc = {}", a, b, c)?;
+        writeln!(out, "ip = {}", self.computer.instruction_pointer)?;
+        writeln!(out, "output = {}", self.computer.print_output())?;
+        Ok(())
+    }
+
+    fn print_trace(&self, count: usize, out: &mut impl Write) -> Result<()> {
+        for step in self
+            .trace
+            .iter()
+            .skip(self.trace.len().saturating_sub(count))
+        {
+            let [a, b, c] = step.registers;
+            writeln!(
+                out,
+                "{:>3}: {:<30} a = {}, b = {}, c = {}",
+                step.instruction_pointer, step.line, a, b, c
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn register_index(register: &str) -> Result<usize> {
+    match REGISTERS.iter().position(|&r| r == register) {
+        Some(index) => Ok(index),
+        None => bail!("Unknown register {:?}, expected a, b or c", register),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+
+    fn session(commands: &str) -> String {
+        let mut out = vec![];
+        debug(INPUT, commands.as_bytes(), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        // The banner and the first location are the same for every session.
+        out.lines()
+            .skip(2)
+            .collect::<Vec<_>>()
+            .join("\n")
+            .replace("(dbg) ", "")
+    }
+
+    #[test]
+    fn test_step_and_registers() {
+        assert_eq!(
+            session("step 2\nr\nset a 8\nset ip 0\ns\nq\n"),
+            "  0: adv 1    ; a = a >> 1
+  2: out a    ; print a % 8
+Output: 4
+Next   4: jnz 0    ; if a != 0 goto 0
+a = 364
+b = 0
+c = 0
+ip = 4
+output = 4
+  0: adv 1    ; a = a >> 1
+Next   2: out a    ; print a % 8
+"
+        );
+    }
+
+    #[test]
+    fn test_breakpoints_and_watchpoints() {
+        assert_eq!(
+            session("b 4\nb\nc\nc\nd 4\nw a\nc\nunwatch a\nc\nt 2\n"),
+            "Breakpoints: 4
+Output: 4
+Breakpoint at 4
+Next   4: jnz 0    ; if a != 0 goto 0
+Output: 6
+Breakpoint at 4
+Next   4: jnz 0    ; if a != 0 goto 0
+Watchpoint a: 182 -> 91
+Next   2: out a    ; print a % 8
+Output: 3
+Output: 5
+Output: 6
+Output: 3
+Output: 5
+Output: 2
+Output: 1
+Output: 0
+Halted with output 4,6,3,5,6,3,5,2,1,0
+  2: out a    ; print a % 8         a = 0, b = 0, c = 0
+  4: jnz 0    ; if a != 0 goto 0    a = 0, b = 0, c = 0
+"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            session("jump\nb 6\nw d\nd 2\nstep x\nset ip 8\ns\n"),
+            "Error: Unknown command \"jump\", type help for the commands
+Error: The program has 6 positions
+Error: Unknown register \"d\", expected a, b or c
+Error: No breakpoint at 2
+Error: invalid digit found in string
+Halted with output 
+"
+        );
+        let mut out = vec![];
+        assert!(debug("Program: 1", &b""[..], &mut out).is_err());
+    }
+}
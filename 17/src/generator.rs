@@ -0,0 +1,52 @@
+//! Random programs shaped like the puzzle's.
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A program hashing register A three bits at a time like the puzzle's, with random constants,
+/// and a register A that makes it print `size` values (between 1 and 20).
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let digits = size.clamp(1, 20) as u32;
+    let register_a = rng.random_range(8u64.pow(digits - 1)..8u64.pow(digits));
+    let program = [
+        2,
+        4,
+        1,
+        rng.random_range(0..8),
+        7,
+        5,
+        4,
+        rng.random_range(0..8),
+        0,
+        3,
+        1,
+        rng.random_range(0..8),
+        5,
+        5,
+        3,
+        0,
+    ];
+    let program: Vec<String> = program.iter().map(u8::to_string).collect();
+    format!(
+        "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        register_a,
+        program.join(",")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day17;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(16, 17);
+        assert_eq!(input, generate(16, 17));
+        let register_a: u64 = input.lines().next().unwrap()[12..].parse().unwrap();
+        assert_eq!(register_a.ilog(8), 15);
+        assert!(input.ends_with(",5,5,3,0\n"));
+        assert_eq!(Day17.part_1(&input).unwrap().split(',').count(), 16);
+    }
+}
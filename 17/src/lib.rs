@@ -0,0 +1,516 @@
+pub mod assembly;
+pub mod debugger;
+pub mod generator;
+pub mod symbolic;
+
+use anyhow::Result;
+use aoc_common::{PuzzleError, PuzzleResult, Scanner, Solution};
+use std::fmt::Display;
+
+/// Programs running longer than this are assumed to never halt.
+const MAX_STEPS: usize = 10_000_000;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn title(&self) -> &'static str {
+        "Chronospatial Computer"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(Computer::parse(input).map(|_| ()).map_err(Into::into))
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(exercise_1(input)?)
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(exercise_2(input)?.to_string())
+    }
+}
+
+struct Computer {
+    tape: Vec<u8>,
+    ax: usize,
+    bx: usize,
+    cx: usize,
+    instruction_pointer: usize,
+    output: Vec<u8>,
+}
+
+enum Operand {
+    Literal(u8),
+    Combo(u8),
+}
+
+enum Instruction {
+    Adv(u8),
+    Bxl(u8),
+    Bst(u8),
+    Jnz(u8),
+    Bxc,
+    Out(u8),
+    Bdv(u8),
+    Cdv(u8),
+}
+
+impl Instruction {
+    /// The instruction for `opcode`, unless it is not a 3-bit number.
+    fn new(opcode: u8, operand: u8) -> Option<Self> {
+        Some(match opcode {
+            0 => Self::Adv(operand),
+            1 => Self::Bxl(operand),
+            2 => Self::Bst(operand),
+            3 => Self::Jnz(operand),
+            4 => Self::Bxc,
+            5 => Self::Out(operand),
+            6 => Self::Bdv(operand),
+            7 => Self::Cdv(operand),
+            _ => return None,
+        })
+    }
+
+    /// The instruction at `position` of the tape, with errors pointing into the tape written out as
+    /// in the input.
+    fn decode(tape: &[u8], position: usize) -> PuzzleResult<Self> {
+        if position + 1 >= tape.len() {
+            return Err(tape_error(
+                tape,
+                tape.len(),
+                "Expected an operand after the last opcode",
+            ));
+        }
+        if let Some(index) = (position..position + 2).find(|&i| tape[i] > 7) {
+            return Err(tape_error(tape, index, "Expected a 3-bit number"));
+        }
+        Ok(Self::new(tape[position], tape[position + 1]).expect("The opcode has 3 bits"))
+    }
+
+    fn extract_operand(&self) -> Operand {
+        match self {
+            Self::Adv(i) => Operand::Combo(*i),
+            Self::Bxl(i) => Operand::Literal(*i),
+            Self::Bst(i) => Operand::Combo(*i),
+            Self::Jnz(i) => Operand::Literal(*i),
+            Self::Bxc => Operand::Literal(0),
+            Self::Out(i) => Operand::Combo(*i),
+            Self::Bdv(i) => Operand::Combo(*i),
+            Self::Cdv(i) => Operand::Combo(*i),
+        }
+    }
+}
+
+impl Computer {
+    /// Reads the registers and the program, such as:
+    ///
+    /// ```text
+    /// Register A: 729
+    /// Register B: 0
+    /// Register C: 0
+    ///
+    /// Program: 0,1,5,4,3,0
+    /// ```
+    ///
+    /// The program must be made of pairs of 3-bit opcodes and operands, and no instruction at an
+    /// even position, where execution starts, may use the reserved combo operand 7.
+    fn parse(input: &str) -> PuzzleResult<Self> {
+        let mut scanner = Scanner::new(input);
+        let mut registers = [0; 3];
+        for (i, register) in registers.iter_mut().enumerate() {
+            scanner.literal(["Register A: ", "\nRegister B: ", "\nRegister C: "][i])?;
+            *register = scanner.number()?;
+        }
+        scanner.literal("\n\nProgram: ")?;
+
+        let mut tape = vec![];
+        let mut positions = vec![];
+        loop {
+            positions.push(scanner.clone());
+            let value: u8 = scanner.number()?;
+            if value > 7 {
+                return Err(positions[tape.len()].error("Expected a 3-bit number"));
+            }
+            tape.push(value);
+            if scanner.literal(",").is_err() {
+                break;
+            }
+        }
+        if tape.len() % 2 == 1 {
+            return Err(scanner.error("Expected an operand after the last opcode"));
+        }
+        for (i, pair) in tape.chunks_exact(2).enumerate() {
+            let instruction = Instruction::new(pair[0], pair[1]).expect("The opcode has 3 bits");
+            if matches!(instruction.extract_operand(), Operand::Combo(7)) {
+                return Err(positions[2 * i + 1].error("Reserved combo operand 7"));
+            }
+        }
+        scanner.finish()?;
+
+        let [ax, bx, cx] = registers;
+        Ok(Self {
+            tape,
+            ax,
+            bx,
+            cx,
+            instruction_pointer: 0,
+            output: vec![],
+        })
+    }
+
+    fn get_operand(&self, instruction: &Instruction) -> PuzzleResult<usize> {
+        let operand = instruction.extract_operand();
+        match operand {
+            Operand::Literal(i) => Ok(i as usize),
+            Operand::Combo(i) => match i {
+                0..=3 => Ok(i as usize),
+                4 => Ok(self.ax),
+                5 => Ok(self.bx),
+                6 => Ok(self.cx),
+                7 => Err(PuzzleError::unsolvable("Reserved combo operand 7")),
+                _ => Err(PuzzleError::unsolvable(format!(
+                    "Unsupported combo operand {}",
+                    i
+                ))),
+            },
+        }
+    }
+
+    fn execute_instruction(&mut self, instruction: &Instruction) -> PuzzleResult<Option<u8>> {
+        let operand = self.get_operand(instruction)?;
+        match instruction {
+            Instruction::Adv(_) | Instruction::Bdv(_) | Instruction::Cdv(_) => {
+                // Dividing by 2**operand, which is 0 once the operand exceeds the register size.
+                let quotient = u32::try_from(operand)
+                    .ok()
+                    .and_then(|shift| self.ax.checked_shr(shift))
+                    .unwrap_or(0);
+                match instruction {
+                    Instruction::Adv(_) => self.ax = quotient,
+                    Instruction::Bdv(_) => self.bx = quotient,
+                    _ => self.cx = quotient,
+                };
+            }
+            Instruction::Bxl(_) => {
+                self.bx ^= operand;
+            }
+            Instruction::Bst(_) => {
+                self.bx = operand % 8;
+            }
+            Instruction::Jnz(_) => {
+                if self.ax != 0 {
+                    self.instruction_pointer = operand;
+                    return Ok(None);
+                }
+            }
+            Instruction::Bxc => {
+                self.bx ^= self.cx;
+            }
+            Instruction::Out(_) => {
+                let out = (operand % 8) as u8;
+                self.output.push(out);
+                self.instruction_pointer += 2;
+                return Ok(Some(out));
+            }
+        }
+        self.instruction_pointer += 2;
+        Ok(None)
+    }
+
+    fn valid_instruction(&self) -> bool {
+        self.tape.len() > 1 && self.instruction_pointer < self.tape.len() - 1
+    }
+
+    fn next_instruction(&self) -> PuzzleResult<Instruction> {
+        Instruction::decode(&self.tape, self.instruction_pointer)
+    }
+
+    fn execution_loop(&mut self) -> PuzzleResult<()> {
+        for _ in 0..MAX_STEPS {
+            if !self.valid_instruction() {
+                return Ok(());
+            }
+            let instruction = self.next_instruction()?;
+            self.execute_instruction(&instruction)?;
+        }
+        Err(PuzzleError::unsolvable(format!(
+            "The program did not halt after {} instructions",
+            MAX_STEPS
+        )))
+    }
+
+    fn print_output(&self) -> String {
+        self.output
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+}
+
+/// A malformed tape, the caret under the value at `index` (or after the last one) of the program
+/// written out as a comma-separated list.
+fn tape_error(tape: &[u8], index: usize, message: impl Display) -> PuzzleError {
+    let values: Vec<String> = tape.iter().map(u8::to_string).collect();
+    let program = values.join(",");
+    if index >= values.len() {
+        return PuzzleError::malformed_at(&program, program.len(), message);
+    }
+    let offset = values[..index].iter().map(|value| value.len() + 1).sum();
+    PuzzleError::malformed_slice(&program, &program[offset..][..values[index].len()], message)
+}
+
+pub fn exercise_1(input_str: &str) -> PuzzleResult<String> {
+    let mut computer = Computer::parse(input_str)?;
+    computer.execution_loop()?;
+    Ok(computer.print_output())
+}
+
+/// Checks that the program is a single loop that prints one value and shifts register A by 3 bits
+/// per iteration, ending on the only jump back to the start, and that sets B and C from scratch in
+/// each iteration before reading them.
+fn check_loop_shape(tape: &[u8]) -> PuzzleResult<()> {
+    let instructions: Vec<&[u8]> = tape.chunks_exact(2).collect();
+    let count = |opcode: u8| instructions.iter().filter(|i| i[0] == opcode).count();
+    if count(3) != 1 || instructions.last() != Some(&&[3, 0][..]) {
+        return Err(PuzzleError::unsolvable(
+            "The program must end with its only jump, back to the start",
+        ));
+    }
+    if count(0) != 1 || !instructions.contains(&&[0, 3][..]) {
+        return Err(PuzzleError::unsolvable(
+            "The program must shift register A by exactly 3 bits per loop",
+        ));
+    }
+    if count(5) != 1 {
+        return Err(PuzzleError::unsolvable(
+            "The program must output exactly one value per loop",
+        ));
+    }
+    // B or C carried over from the previous loop would make the values printed depend on lower
+    // bits of A than the ones already chosen.
+    let mut is_set = [false; 2];
+    for position in (0..instructions.len()).map(|i| 2 * i) {
+        let instruction = Instruction::decode(tape, position)?;
+        let mut reads = vec![];
+        if let Operand::Combo(register @ 5..=6) = instruction.extract_operand() {
+            reads.push(register as usize - 5);
+        }
+        match instruction {
+            Instruction::Bxl(_) => reads.push(0),
+            Instruction::Bxc => reads.extend([0, 1]),
+            _ => {}
+        }
+        if let Some(&register) = reads.iter().find(|&&register| !is_set[register]) {
+            return Err(PuzzleError::unsolvable(format!(
+                "The program must set register {} before reading it in each loop",
+                ["B", "C"][register]
+            )));
+        }
+        match instruction {
+            Instruction::Bst(_) | Instruction::Bdv(_) => is_set[0] = true,
+            Instruction::Cdv(_) => is_set[1] = true,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Finds the smallest register A that makes the program print itself.
+///
+/// Each loop consumes the lowest 3 bits of A, so the last value printed only depends on its
+/// highest 3 bits. A is built from there, 3 bits at a time, keeping the candidates that print the
+/// end of the program and backtracking when none of the 8 do.
+pub fn exercise_2(input_str: &str) -> PuzzleResult<usize> {
+    let computer = Computer::parse(input_str)?;
+    check_loop_shape(&computer.tape)?;
+    if computer.tape.len() * 3 > usize::BITS as usize {
+        return Err(PuzzleError::unsolvable(
+            "The program is too long to reproduce",
+        ));
+    }
+    find_quine(&computer, 0, computer.tape.len())
+        .ok_or_else(|| PuzzleError::unsolvable("No register value reproduces the program"))
+}
+
+/// The smallest extension of `prefix` printing the program from `remaining` onwards.
+fn find_quine(computer: &Computer, prefix: usize, remaining: usize) -> Option<usize> {
+    if remaining == 0 {
+        return Some(prefix);
+    }
+    (0..8).find_map(|bits| {
+        let ax = (prefix << 3) | bits;
+        let mut candidate = Computer {
+            tape: computer.tape.clone(),
+            ax,
+            bx: computer.bx,
+            cx: computer.cx,
+            instruction_pointer: 0,
+            output: Vec::with_capacity(computer.tape.len()),
+        };
+        let prints_suffix = candidate.execution_loop().is_ok()
+            && candidate.output == computer.tape[remaining - 1..];
+        prints_suffix
+            .then(|| find_quine(computer, ax, remaining - 1))
+            .flatten()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    fn input(ax: usize, program: &str) -> String {
+        format!(
+            "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            ax, program
+        )
+    }
+
+    #[test]
+    fn test_exercise_1() {
+        assert_eq!(
+            exercise_1(&input(729, "0,1,5,4,3,0")).unwrap(),
+            "4,6,3,5,6,3,5,2,1,0"
+        );
+    }
+
+    #[test]
+    fn test_exercise_2() {
+        assert_eq!(exercise_2(&input(2024, "0,3,5,4,3,0")).unwrap(), 117440);
+    }
+
+    #[test]
+    fn test_invalid_programs() {
+        assert!(exercise_1(&input(1, "3,0")).is_err());
+        assert_eq!(exercise_1(&input(1 << 40, "0,4,5,4")).unwrap(), "0");
+        // Jumping to an odd position reads operands as opcodes, which the parser cannot check.
+        assert!(Computer::parse(&input(1, "3,1,5,0,7,0")).is_ok());
+        assert!(exercise_1(&input(1, "3,1,5,0,7,0")).is_err());
+        assert!(exercise_2(&input(0, "5,7,3,0")).is_err());
+    }
+
+    #[rstest]
+    #[case(
+        "0,3,5,4",
+        "Unsolvable input: The program must end with its only jump, back to the start"
+    )]
+    #[case(
+        "3,0,0,3,5,4,3,0",
+        "Unsolvable input: The program must end with its only jump, back to the start"
+    )]
+    #[case(
+        "0,1,5,4,3,0",
+        "Unsolvable input: The program must shift register A by exactly 3 bits per loop"
+    )]
+    #[case(
+        "0,3,0,1,5,4,3,0",
+        "Unsolvable input: The program must shift register A by exactly 3 bits per loop"
+    )]
+    #[case(
+        "0,3,5,4,5,4,3,0",
+        "Unsolvable input: The program must output exactly one value per loop"
+    )]
+    #[case(
+        "0,3,5,1,3,0",
+        "Unsolvable input: No register value reproduces the program"
+    )]
+    #[case(
+        "0,3,1,1,5,5,3,0",
+        "Unsolvable input: The program must set register B before reading it in each loop"
+    )]
+    #[case(
+        "2,4,0,3,4,0,5,5,3,0",
+        "Unsolvable input: The program must set register C before reading it in each loop"
+    )]
+    #[case(
+        "6,4,0,3,5,6,3,0",
+        "Unsolvable input: The program must set register C before reading it in each loop"
+    )]
+    #[case(
+        "0,3,2,4,1,0,1,0,1,0,1,0,1,0,1,0,1,0,5,4,3,0",
+        "Unsolvable input: The program is too long to reproduce"
+    )]
+    fn test_exercise_2_errors(#[case] program: &str, #[case] expected: &str) {
+        assert_eq!(
+            exercise_2(&input(0, program)).err().unwrap().to_string(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_exercise_2_generated() {
+        // Random constants only make some generated programs able to print themselves.
+        let quines = (0..16)
+            .map(|seed| crate::generator::generate(16, seed))
+            .filter_map(|program| Some((exercise_2(&program).ok()?, program)))
+            .collect::<Vec<_>>();
+        assert!(!quines.is_empty());
+        for (ax, program) in quines {
+            let mut computer = Computer::parse(&program).unwrap();
+            computer.ax = ax;
+            computer.execution_loop().unwrap();
+            assert_eq!(computer.output, computer.tape);
+        }
+    }
+
+    #[rstest]
+    #[case(
+        input(1, "8,0"),
+        "Line 5, column 10: Expected a 3-bit number\n5 | Program: 8,0\n  |          ^"
+    )]
+    #[case(
+        input(1, "5,7"),
+        "Line 5, column 12: Reserved combo operand 7\n5 | Program: 5,7\n  |            ^"
+    )]
+    #[case(
+        input(1, "0,1,5"),
+        "Line 5, column 15: Expected an operand after the last opcode\n5 | Program: 0,1,5\n  |               ^"
+    )]
+    #[case(
+        "Register A: 1\nRegister C: 0\n".to_string(),
+        "Line 2, column 10: Expected \"\\nRegister B: \"\n2 | Register C: 0\n  |          ^"
+    )]
+    #[case(
+        input(1, "0,1,"),
+        "Line 5, column 14: Expected a number\n5 | Program: 0,1,\n  |              ^"
+    )]
+    fn test_parse_errors(#[case] input: String, #[case] expected: &str) {
+        assert_eq!(Computer::parse(&input).err().unwrap().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case(
+        &[0, 3, 12, 0],
+        2,
+        "Line 1, column 5: Expected a 3-bit number\n1 | 0,3,12,0\n  |     ^^"
+    )]
+    #[case(
+        &[5, 4, 3, 9],
+        2,
+        "Line 1, column 7: Expected a 3-bit number\n1 | 5,4,3,9\n  |       ^"
+    )]
+    #[case(
+        &[0, 3, 5],
+        2,
+        "Line 1, column 6: Expected an operand after the last opcode\n1 | 0,3,5\n  |      ^"
+    )]
+    fn test_decode_errors(#[case] tape: &[u8], #[case] position: usize, #[case] expected: &str) {
+        assert_eq!(
+            Instruction::decode(tape, position)
+                .err()
+                .unwrap()
+                .to_string(),
+            expected
+        );
+    }
+}
@@ -0,0 +1,288 @@
+//! Symbolic execution of the programs that loop over register A 3 bits at a time: one pass through
+//! the loop gives the value it prints as an expression of `a`, register A at the start of that
+//! loop, which is the starting A shifted right by 3 bits per loop done before.
+
+use crate::{check_loop_shape, tape_error, Computer, Instruction, Operand};
+use aoc_common::{PuzzleError, PuzzleResult};
+use std::fmt::{self, Display};
+
+/// Programs longer than this may build expressions too large to print, as every instruction can
+/// double their size.
+const MAX_TAPE_LENGTH: usize = 64;
+
+/// A value computed by the program from the registers at the start of a loop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Const(usize),
+    /// A register at the start of the loop: 0 for A, 1 for B and 2 for C.
+    Register(u8),
+    Xor(Box<Expr>, Box<Expr>),
+    Shr(Box<Expr>, Box<Expr>),
+    Mod8(Box<Expr>),
+}
+
+impl Expr {
+    fn xor(left: Expr, right: Expr) -> Expr {
+        match (left, right) {
+            (Expr::Const(l), Expr::Const(r)) => Expr::Const(l ^ r),
+            (Expr::Const(0), other) | (other, Expr::Const(0)) => other,
+            (left, right) => Expr::Xor(Box::new(left), Box::new(right)),
+        }
+    }
+
+    fn shr(value: Expr, shift: Expr) -> Expr {
+        match (value, shift) {
+            (value, Expr::Const(0)) => value,
+            (Expr::Const(v), Expr::Const(s)) => Expr::Const(shift_right(v, s)),
+            (Expr::Shr(inner, first), Expr::Const(s)) => match *first {
+                Expr::Const(f) => Expr::shr(*inner, Expr::Const(f.saturating_add(s))),
+                first => Expr::Shr(
+                    Box::new(Expr::Shr(inner, Box::new(first))),
+                    Box::new(Expr::Const(s)),
+                ),
+            },
+            (value, shift) => Expr::Shr(Box::new(value), Box::new(shift)),
+        }
+    }
+
+    fn mod8(value: Expr) -> Expr {
+        match value {
+            Expr::Const(v) => Expr::Const(v % 8),
+            value if value.is_3_bit() => value,
+            value => Expr::Mod8(Box::new(value)),
+        }
+    }
+
+    /// Whether the value is known to be below 8.
+    fn is_3_bit(&self) -> bool {
+        match self {
+            Expr::Const(v) => *v < 8,
+            Expr::Mod8(_) => true,
+            Expr::Xor(left, right) => left.is_3_bit() && right.is_3_bit(),
+            _ => false,
+        }
+    }
+
+    /// The value for the given registers at the start of the loop.
+    pub fn eval(&self, registers: [usize; 3]) -> usize {
+        match self {
+            Expr::Const(v) => *v,
+            Expr::Register(r) => registers[*r as usize],
+            Expr::Xor(left, right) => left.eval(registers) ^ right.eval(registers),
+            Expr::Shr(left, right) => shift_right(left.eval(registers), right.eval(registers)),
+            Expr::Mod8(value) => value.eval(registers) % 8,
+        }
+    }
+}
+
+/// Like the machine, shifting out every bit once the shift exceeds the register size.
+fn shift_right(value: usize, shift: usize) -> usize {
+    u32::try_from(shift)
+        .ok()
+        .and_then(|shift| value.checked_shr(shift))
+        .unwrap_or(0)
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operand = |f: &mut fmt::Formatter<'_>, expr: &Expr| match expr {
+            Expr::Const(_) | Expr::Register(_) => write!(f, "{}", expr),
+            _ => write!(f, "({})", expr),
+        };
+        match self {
+            Expr::Const(v) => write!(f, "{}", v),
+            Expr::Register(r) => write!(f, "{}", ["a", "b", "c"][*r as usize]),
+            Expr::Xor(left, right) => {
+                operand(f, left)?;
+                write!(f, " ^ ")?;
+                operand(f, right)
+            }
+            Expr::Shr(left, right) => {
+                operand(f, left)?;
+                write!(f, " >> ")?;
+                operand(f, right)
+            }
+            Expr::Mod8(value) => {
+                operand(f, value)?;
+                write!(f, " % 8")
+            }
+        }
+    }
+}
+
+/// Runs one loop of the program on symbolic registers, giving the value it prints in terms of
+/// register A at the start of the loop.
+///
+/// Fails unless the program has the loop shape `exercise_2` expects, which also makes sure that
+/// what it prints does not depend on registers B or C left by the previous loop.
+pub fn loop_output(tape: &[u8]) -> PuzzleResult<Expr> {
+    if !tape.len().is_multiple_of(2) {
+        return Err(tape_error(
+            tape,
+            tape.len(),
+            "Expected an operand after the last opcode",
+        ));
+    }
+    check_loop_shape(tape)?;
+    if tape.len() > MAX_TAPE_LENGTH {
+        return Err(PuzzleError::unsolvable(format!(
+            "Symbolic execution supports programs of up to {} values",
+            MAX_TAPE_LENGTH
+        )));
+    }
+    let mut registers = [0, 1, 2].map(Expr::Register);
+    let mut output = None;
+    for position in (0..tape.len()).step_by(2) {
+        let instruction = Instruction::decode(tape, position)?;
+        let operand = match instruction.extract_operand() {
+            Operand::Literal(value) => Expr::Const(value as usize),
+            Operand::Combo(value @ 0..=3) => Expr::Const(value as usize),
+            Operand::Combo(value @ 4..=6) => registers[value as usize - 4].clone(),
+            Operand::Combo(_) => {
+                return Err(tape_error(tape, position + 1, "Reserved combo operand 7"))
+            }
+        };
+        let [a, b, c] = &mut registers;
+        match instruction {
+            Instruction::Adv(_) => *a = Expr::shr(a.clone(), operand),
+            Instruction::Bdv(_) => *b = Expr::shr(a.clone(), operand),
+            Instruction::Cdv(_) => *c = Expr::shr(a.clone(), operand),
+            Instruction::Bxl(_) => *b = Expr::xor(b.clone(), operand),
+            Instruction::Bst(_) => *b = Expr::mod8(operand),
+            Instruction::Bxc => *b = Expr::xor(b.clone(), c.clone()),
+            Instruction::Out(_) => output = Some(Expr::mod8(operand)),
+            Instruction::Jnz(_) => {}
+        }
+    }
+    Ok(output.expect("The loop shape has one output"))
+}
+
+/// The smallest register A for which a loop printing `output` prints `values`.
+///
+/// The last loop only sees the highest 3 bits of A, so A is built from there like in
+/// `exercise_2`, but checking each candidate against the formula instead of running the program.
+///
+/// Fails when printing `values` takes more loops than register A has bits for.
+pub fn solve(output: &Expr, values: &[u8]) -> PuzzleResult<Option<usize>> {
+    if values.len() * 3 > usize::BITS as usize {
+        return Err(PuzzleError::unsolvable(format!(
+            "Printing {} values takes more than the {} bits of register A",
+            values.len(),
+            usize::BITS
+        )));
+    }
+    if values.is_empty() {
+        return Ok(None);
+    }
+    Ok(solve_from(output, values, 0, values.len()))
+}
+
+fn solve_from(output: &Expr, values: &[u8], prefix: usize, remaining: usize) -> Option<usize> {
+    if remaining == 0 {
+        return Some(prefix);
+    }
+    // A must not run out before the last loop, which starts on its highest bits.
+    let lowest = usize::from(remaining == values.len() && values.len() > 1);
+    (lowest..8).find_map(|bits| {
+        let ax = (prefix << 3) | bits;
+        (output.eval([ax, 0, 0]) == values[remaining - 1] as usize)
+            .then(|| solve_from(output, values, ax, remaining - 1))
+            .flatten()
+    })
+}
+
+/// The formula printed by the program of `input`, and the smallest A printing `values`, the
+/// program itself by default.
+pub fn describe(input: &str, values: Option<&[u8]>) -> PuzzleResult<String> {
+    let computer = Computer::parse(input)?;
+    let output = loop_output(&computer.tape)?;
+    let values = values.unwrap_or(&computer.tape);
+    let printed: Vec<String> = values.iter().map(u8::to_string).collect();
+    let solution = match solve(&output, values)? {
+        Some(ax) => format!("A = {} prints {}", ax, printed.join(",")),
+        None => format!("No value of A prints {}", printed.join(",")),
+    };
+    Ok(format!(
+        "Loop k starts with a = A >> 3k, and prints\nout = {}\n{}",
+        output, solution
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator;
+
+    fn tape(program: &str) -> Vec<u8> {
+        program.split(',').map(|v| v.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_loop_output() {
+        let output = loop_output(&tape("2,4,1,1,7,5,4,6,0,3,1,4,5,5,3,0")).unwrap();
+        assert_eq!(
+            output.to_string(),
+            "((((a % 8) ^ 1) ^ (a >> ((a % 8) ^ 1))) ^ 4) % 8"
+        );
+        let output = loop_output(&tape("0,3,5,4,3,0")).unwrap();
+        assert_eq!(output.to_string(), "(a >> 3) % 8");
+        assert_eq!(solve(&output, &tape("0,3,5,4,3,0")).unwrap(), Some(117440));
+    }
+
+    #[test]
+    fn test_loop_output_errors() {
+        assert_eq!(
+            loop_output(&tape("0,3,1,1,5,5,3,0"))
+                .unwrap_err()
+                .to_string(),
+            "Unsolvable input: The program must set register B before reading it in each loop"
+        );
+        assert!(loop_output(&tape("0,3,5,4")).is_err());
+    }
+
+    #[test]
+    fn test_matches_the_machine() {
+        for seed in 0..16 {
+            let input = generator::generate(12, seed);
+            let computer = Computer::parse(&input).unwrap();
+            let output = loop_output(&computer.tape).unwrap();
+            let printed: Vec<String> = (0..12)
+                .map(|k| output.eval([computer.ax >> (3 * k), 0, 0]).to_string())
+                .collect();
+            assert_eq!(printed.join(","), crate::exercise_1(&input).unwrap());
+
+            let values = tape(&printed.join(","));
+            let ax = solve(&output, &values).unwrap().unwrap();
+            assert!(ax <= computer.ax);
+            let mut solved = Computer::parse(&input).unwrap();
+            solved.ax = ax;
+            solved.execution_loop().unwrap();
+            assert_eq!(solved.output, values);
+            assert_eq!(
+                solve(&output, &computer.tape).unwrap(),
+                crate::exercise_2(&input).ok()
+            );
+        }
+    }
+
+    #[test]
+    fn test_describe() {
+        let input = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0\n";
+        assert_eq!(
+            describe(input, None).unwrap(),
+            "Loop k starts with a = A >> 3k, and prints\nout = (a >> 3) % 8\nA = 117440 prints 0,3,5,4,3,0"
+        );
+        assert_eq!(
+            describe(input, Some(&[3, 0])).unwrap().lines().last(),
+            Some("A = 24 prints 3,0")
+        );
+        assert_eq!(
+            describe(input, Some(&[1])).unwrap().lines().last(),
+            Some("No value of A prints 1")
+        );
+        assert_eq!(
+            describe(input, Some(&[0; 22])).unwrap_err().to_string(),
+            "Unsolvable input: Printing 22 values takes more than the 64 bits of register A"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Random falling bytes in the 71x71 memory space.
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const SIDE: usize = 71;
+/// Bytes fallen when the first part looks for a path.
+const FALLEN: usize = 1024;
+
+/// `size` distinct bytes, between 1095 and every position but the corners. The first 1024 keep
+/// a random staircase path to the exit open, then a full row falls in random order so that the
+/// exit is cut off, and the rest land anywhere.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut path = vec![false; SIDE * SIDE];
+    let (mut x, mut y) = (0, 0);
+    while (x, y) != (SIDE - 1, SIDE - 1) {
+        path[y * SIDE + x] = true;
+        if y == SIDE - 1 || (x < SIDE - 1 && rng.random_bool(0.5)) {
+            x += 1;
+        } else {
+            y += 1;
+        }
+    }
+    path[y * SIDE + x] = true;
+    let barrier = rng.random_range(1..SIDE - 1);
+
+    let mut positions: Vec<(usize, usize)> = (0..SIDE * SIDE)
+        .map(|i| (i % SIDE, i / SIDE))
+        .filter(|&p| p != (0, 0) && p != (SIDE - 1, SIDE - 1))
+        .collect();
+    positions.shuffle(&mut rng);
+    let (mut open, mut rest): (Vec<_>, Vec<_>) = positions
+        .into_iter()
+        .partition(|&(x, y)| !path[y * SIDE + x] && y != barrier);
+    rest.extend(open.split_off(FALLEN));
+    rest.sort_by_key(|&(_, y)| y != barrier);
+    rest[SIDE..].shuffle(&mut rng);
+
+    open.iter()
+        .chain(&rest)
+        .take(size.clamp(FALLEN + SIDE, SIDE * SIDE - 2))
+        .map(|(x, y)| format!("{},{}\n", x, y))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day18;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(2000, 18);
+        assert_eq!(input, generate(2000, 18));
+        assert_eq!(input.lines().count(), 2000);
+        assert!(Day18.part_1(&input).is_ok());
+        let barrier: Vec<&str> = input
+            .lines()
+            .skip(FALLEN)
+            .take(SIDE)
+            .map(|line| line.split_once(',').unwrap().1)
+            .collect();
+        assert!(barrier.iter().all(|&y| y == barrier[0]));
+        assert_eq!(generate(0, 18).lines().count(), FALLEN + SIDE);
+    }
+}
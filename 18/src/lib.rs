@@ -0,0 +1,181 @@
+pub mod generator;
+
+use anyhow::Result;
+use aoc_common::{Part, PuzzleError, PuzzleResult, Solution};
+use generic_search::{bfs, bidirectional_bfs, Node};
+use grid::Grid;
+use std::{
+    collections::HashSet,
+    io::{self, BufRead},
+};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn title(&self) -> &'static str {
+        "RAM Run"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(
+            Maze::read(input.as_bytes(), 71, 71, 1024)
+                .map(|_| ())
+                .map_err(Into::into),
+        )
+    }
+
+    fn solve_reader(&self, part: Part, input: &mut dyn BufRead) -> Option<Result<String>> {
+        let answer = match part {
+            Part::One => exercise_1(input, 71, 71, 1024).map(|n| n.to_string()),
+            Part::Two => exercise_2(input, 71, 71).map(|(x, y)| format!("{},{}", x, y)),
+        };
+        Some(answer.map_err(Into::into))
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(exercise_1(input.as_bytes(), 71, 71, 1024)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        let (x, y) = exercise_2(input.as_bytes(), 71, 71)?;
+        Ok(format!("{},{}", x, y))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Cell {
+    Empty,
+    Blocked,
+}
+
+struct Maze {
+    cells: Grid<Cell>,
+    start: (usize, usize),
+    goal: (usize, usize),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, std::hash::Hash)]
+struct State((usize, usize));
+
+/// Drops the bytes one at a time as they are read, so only the maze is held in memory.
+///
+/// The exit stays reachable until a byte lands on the last path found to it, so the maze is only
+/// searched again then.
+pub fn exercise_2(
+    buffered: impl BufRead,
+    rows: usize,
+    cols: usize,
+) -> PuzzleResult<(usize, usize)> {
+    let mut maze = Maze::read(io::empty(), rows, cols, 0)?;
+    let mut path = HashSet::new();
+
+    for (index, line) in buffered.lines().enumerate() {
+        let (x, y) = parse_block(index, &line?, rows, cols)?;
+        maze.cells[(x, y)] = Cell::Blocked;
+        if !path.is_empty() && !path.contains(&(x, y)) {
+            continue;
+        }
+        match maze.bidirectional_bfs() {
+            Some(states) => path = states.into_iter().map(|State(position)| position).collect(),
+            None => return Ok((x, y)),
+        }
+    }
+    Err(PuzzleError::unsolvable("The exit is never cut off"))
+}
+
+/// Parses the `index`-th line of the input, a `x,y` position inside the memory space.
+fn parse_block(index: usize, line: &str, rows: usize, cols: usize) -> PuzzleResult<(usize, usize)> {
+    let (x_str, y_str) = line
+        .split_once(',')
+        .ok_or_else(|| PuzzleError::malformed(index, 0, line, "Expected `x,y`"))?;
+    let x = x_str
+        .parse::<usize>()
+        .map_err(|e| PuzzleError::malformed_token(index, line, x_str, e))?;
+    let y = y_str
+        .parse::<usize>()
+        .map_err(|e| PuzzleError::malformed_token(index, line, y_str, e))?;
+    if x >= cols {
+        return Err(PuzzleError::malformed_token(
+            index,
+            line,
+            x_str,
+            "x out of bounds",
+        ));
+    }
+    if y >= rows {
+        return Err(PuzzleError::malformed_token(
+            index,
+            line,
+            y_str,
+            "y out of bounds",
+        ));
+    }
+    Ok((x, y))
+}
+
+impl Maze {
+    /// A maze with the first `bytes` lines of `buffered` fallen, leaving the rest unread.
+    fn read(buffered: impl BufRead, rows: usize, cols: usize, bytes: usize) -> PuzzleResult<Self> {
+        if rows == 0 || cols == 0 {
+            return Err(PuzzleError::unsolvable("The memory space is empty"));
+        }
+        let start = (0, 0);
+        let goal = (cols - 1, rows - 1);
+
+        let mut cells = Grid::new(cols, rows, Cell::Empty);
+
+        for (index, line) in buffered.lines().enumerate().take(bytes) {
+            let (x, y) = parse_block(index, &line?, rows, cols)?;
+            cells[(x, y)] = Cell::Blocked;
+        }
+
+        Ok(Self { cells, start, goal })
+    }
+
+    fn goal_test(&self, state: &State) -> bool {
+        state.0 == self.goal
+    }
+
+    fn successors(&self, state: &State) -> Vec<State> {
+        self.cells
+            .neighbors4(state.0)
+            .filter(|&position| self.cells[position] == Cell::Empty)
+            .map(State)
+            .collect()
+    }
+
+    fn bfs(&self) -> Option<Node<State>> {
+        let successors = |state: &State| self.successors(state);
+        let goal_test = |state: &State| self.goal_test(state);
+        bfs(State(self.start), goal_test, successors)
+    }
+
+    fn bidirectional_bfs(&self) -> Option<Vec<State>> {
+        // Moves are reversible, so the successors are also the predecessors.
+        let successors = |state: &State| self.successors(state);
+        bidirectional_bfs(State(self.start), State(self.goal), successors, successors)
+    }
+
+    fn bfs_path_length(&self) -> Option<usize> {
+        self.bfs().map(|solution| solution.depth())
+    }
+}
+
+pub fn exercise_1(
+    buffered: impl BufRead,
+    rows: usize,
+    cols: usize,
+    bytes: usize,
+) -> PuzzleResult<usize> {
+    let maze = Maze::read(buffered, rows, cols, bytes)?;
+    maze.bfs_path_length()
+        .ok_or_else(|| PuzzleError::unsolvable("No path found"))
+}
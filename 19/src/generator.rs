@@ -0,0 +1,58 @@
+//! Random towels and designs.
+
+use rand::{rngs::StdRng, seq::IndexedRandom, Rng, SeedableRng};
+use std::collections::BTreeSet;
+
+const COLORS: &[u8] = b"wubrg";
+
+fn stripes(rng: &mut StdRng, length: usize) -> String {
+    (0..length)
+        .map(|_| char::from(*COLORS.choose(rng).unwrap()))
+        .collect()
+}
+
+/// 100 towels of 2 to 8 stripes, plus single stripes of three colors, and `size` designs, at
+/// least one, of 20 to 40 stripes. Half of the designs are made of towels, the others are random
+/// and only sometimes possible.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut towels: BTreeSet<String> = ["w", "u", "g"].map(String::from).into();
+    while towels.len() < 103 {
+        let length = rng.random_range(2..=8);
+        towels.insert(stripes(&mut rng, length));
+    }
+    let towels: Vec<String> = towels.into_iter().collect();
+
+    let mut input = towels.join(", ") + "\n\n";
+    for _ in 0..size.max(1) {
+        let length = rng.random_range(20..=40);
+        let design = if rng.random_bool(0.5) {
+            let mut design = String::new();
+            while design.len() < length {
+                design.push_str(towels.choose(&mut rng).unwrap());
+            }
+            design
+        } else {
+            stripes(&mut rng, length)
+        };
+        input.push_str(&design);
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day19;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(400, 19);
+        assert_eq!(input, generate(400, 19));
+        let possible: usize = Day19.part_1(&input).unwrap().parse().unwrap();
+        assert!((200..400).contains(&possible));
+        assert!(Day19.part_2(&input).is_ok());
+    }
+}
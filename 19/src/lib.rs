@@ -0,0 +1,283 @@
+pub mod generator;
+
+use anyhow::Result;
+use aoc_common::{Part, PuzzleError, PuzzleResult, Solution};
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn title(&self) -> &'static str {
+        "Linen Layout"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(for_each_pattern(input.as_bytes(), |_, _| ()).map_err(Into::into))
+    }
+
+    fn solve_reader(&self, part: Part, input: &mut dyn BufRead) -> Option<Result<String>> {
+        let answer = match part {
+            Part::One => exercise(input).map(|(possible, _)| possible.to_string()),
+            Part::Two => exercise(input).map(|(_, counts)| counts.to_string()),
+        };
+        Some(answer.map_err(Into::into))
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        let (possible, _) = exercise(input.as_bytes())?;
+        Ok(possible.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        let (_, counts) = exercise(input.as_bytes())?;
+        Ok(counts.to_string())
+    }
+}
+
+/// Reads the towels, up to the blank line, then calls `f` on each pattern as soon as it is read.
+fn for_each_pattern<F>(buffered: impl BufRead, mut f: F) -> PuzzleResult<()>
+where
+    F: FnMut(&str, &HashSet<String>),
+{
+    let mut lines = buffered.lines().enumerate();
+
+    let mut towels: HashSet<String> = HashSet::new();
+    let mut last_line = (0, String::new());
+    loop {
+        let Some((index, line)) = lines.next() else {
+            let (index, line) = last_line;
+            return Err(PuzzleError::malformed(
+                index,
+                line.trim_end().len(),
+                &line,
+                "Expected the towels and the patterns separated by a blank line",
+            ));
+        };
+        let line = line?;
+        if line.trim().is_empty() {
+            break;
+        }
+        for towel in line.split(", ") {
+            if towel.trim().is_empty() {
+                return Err(PuzzleError::malformed_token(
+                    index,
+                    &line,
+                    towel,
+                    "Empty towel",
+                ));
+            }
+            towels.insert(towel.to_string());
+        }
+        last_line = (index, line);
+    }
+
+    let mut n_patterns = 0;
+    for (_, line) in lines {
+        let pattern = line?;
+        if pattern.trim().is_empty() {
+            continue;
+        }
+        n_patterns += 1;
+        f(&pattern, &towels);
+    }
+    if n_patterns == 0 {
+        return Err(PuzzleError::unsolvable("No patterns to build"));
+    }
+    Ok(())
+}
+
+/// Counts the patterns that can be built, and the ways to build them, one pattern at a time.
+pub fn exercise(buffered: impl BufRead) -> PuzzleResult<(usize, usize)> {
+    let (mut possible, mut counts) = (0, 0);
+    for_each_pattern(buffered, |pattern, towels| {
+        // The memos only live as long as their pattern, so memory does not grow with the input.
+        let mut memo_possible = HashMap::new();
+        if pattern_possible(pattern, towels, &mut memo_possible) {
+            possible += 1;
+            counts +=
+                ways_to_build_pattern(pattern, towels, &mut memo_possible, &mut HashMap::new());
+        }
+    })?;
+    Ok((possible, counts))
+}
+
+fn pattern_possible(
+    pattern: &str,
+    towels: &HashSet<String>,
+    memo_possible: &mut HashMap<String, bool>,
+) -> bool {
+    if let Some(&result) = memo_possible.get(pattern) {
+        return result;
+    }
+
+    if towels.contains(pattern) || pattern.is_empty() {
+        return true;
+    }
+
+    // Towels are split off at character boundaries, in case the pattern is not ASCII.
+    let result = (1..=(pattern.len() - 1))
+        .filter(|&k| pattern.is_char_boundary(k))
+        .any(|k| {
+            let (s_left, s_right) = pattern.split_at(k);
+            towels.contains(s_left) && pattern_possible(s_right, towels, memo_possible)
+        });
+
+    memo_possible
+        .entry(pattern.to_string())
+        .insert_entry(result);
+    result
+}
+
+fn ways_to_build_pattern(
+    pattern: &str,
+    towels: &HashSet<String>,
+    memo_possible: &mut HashMap<String, bool>,
+    memo_counts: &mut HashMap<String, usize>,
+) -> usize {
+    if let Some(&result) = memo_counts.get(pattern) {
+        return result;
+    }
+
+    if let Some(&result) = memo_possible.get(pattern) {
+        if !result {
+            return 0;
+        }
+    }
+
+    if pattern.is_empty() {
+        return 1;
+    }
+
+    let count = (1..=(pattern.len()))
+        .filter(|&k| pattern.is_char_boundary(k))
+        .filter_map(|k| {
+            let (s_left, s_right) = pattern.split_at(k);
+            if towels.contains(s_left) && pattern_possible(s_right, towels, memo_possible) {
+                return Some(ways_to_build_pattern(
+                    s_right,
+                    towels,
+                    memo_possible,
+                    memo_counts,
+                ));
+            }
+            None
+        })
+        .sum();
+
+    memo_counts.entry(pattern.to_string()).insert_entry(count);
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exercise_errors() {
+        assert_eq!(
+            exercise("r, wr\nbrwrr\n".as_bytes())
+                .unwrap_err()
+                .to_string(),
+            "Line 2, column 6: Expected the towels and the patterns separated by a blank line\n\
+             2 | brwrr\n  |      ^"
+        );
+        assert_eq!(
+            exercise("r, , wr\n\nbrwrr\n".as_bytes())
+                .unwrap_err()
+                .to_string(),
+            "Line 1, column 4: Empty towel\n1 | r, , wr\n  |    ^"
+        );
+        assert!(exercise("r, wr\n\n\n".as_bytes()).is_err());
+        assert_eq!(exercise("r, wr\n\nrwr\nw\n".as_bytes()).unwrap(), (1, 1));
+        assert_eq!(
+            exercise("r, é\n\nrér\nré\u{1f600}\n".as_bytes()).unwrap(),
+            (1, 1)
+        );
+    }
+
+    #[test]
+    fn test_pattern_possible() {
+        let towels: HashSet<String> = HashSet::from([
+            "r".to_string(),
+            "wr".to_string(),
+            "b".to_string(),
+            "g".to_string(),
+            "bwu".to_string(),
+            "rb".to_string(),
+            "gb".to_string(),
+            "br".to_string(),
+        ]);
+
+        let mut memo = HashMap::new();
+
+        assert!(pattern_possible("brwrr", &towels, &mut memo));
+        assert!(pattern_possible("bggr", &towels, &mut memo));
+        assert!(pattern_possible("gbbr", &towels, &mut memo));
+        assert!(pattern_possible("rrbgbr", &towels, &mut memo));
+        assert!(!pattern_possible("ubwu", &towels, &mut memo));
+        assert!(pattern_possible("bwurrg", &towels, &mut memo));
+        assert!(pattern_possible("brgr", &towels, &mut memo));
+        assert!(!pattern_possible("bbrgwb", &towels, &mut memo));
+    }
+
+    #[test]
+    fn test_ways_to_build_pattern() {
+        let towels: HashSet<String> = HashSet::from([
+            "r".to_string(),
+            "wr".to_string(),
+            "b".to_string(),
+            "g".to_string(),
+            "bwu".to_string(),
+            "rb".to_string(),
+            "gb".to_string(),
+            "br".to_string(),
+        ]);
+
+        let mut memo_possible = HashMap::new();
+        let mut memo_counts = HashMap::new();
+
+        assert_eq!(
+            ways_to_build_pattern("brwrr", &towels, &mut memo_possible, &mut memo_counts),
+            2
+        );
+        assert_eq!(
+            ways_to_build_pattern("bggr", &towels, &mut memo_possible, &mut memo_counts),
+            1
+        );
+        assert_eq!(
+            ways_to_build_pattern("gbbr", &towels, &mut memo_possible, &mut memo_counts),
+            4
+        );
+        assert_eq!(
+            ways_to_build_pattern("rrbgbr", &towels, &mut memo_possible, &mut memo_counts),
+            6
+        );
+        assert_eq!(
+            ways_to_build_pattern("bwurrg", &towels, &mut memo_possible, &mut memo_counts),
+            1
+        );
+        assert_eq!(
+            ways_to_build_pattern("brgr", &towels, &mut memo_possible, &mut memo_counts),
+            2
+        );
+        assert_eq!(
+            ways_to_build_pattern("ubwu", &towels, &mut memo_possible, &mut memo_counts),
+            0
+        );
+        assert_eq!(
+            ways_to_build_pattern("bbrgwb", &towels, &mut memo_possible, &mut memo_counts),
+            0
+        );
+    }
+}
@@ -0,0 +1,68 @@
+//! Random racetracks.
+
+use grid::Grid;
+use rand::{rngs::StdRng, seq::IndexedRandom, SeedableRng};
+
+/// A square map of side `size`, rounded up to an odd number of at least 5, with a single winding
+/// track from the start in the bottom left corner to the end in the top right one. The track is
+/// the branch of a random depth-first walk that reaches the end, so it has no dead ends and its
+/// turns are one wall apart.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let side = size.max(5) | 1;
+    let (start, end) = ((1, side - 2), (side - 2, 1));
+
+    let mut visited = Grid::new(side, side, false);
+    visited[start] = true;
+    let mut stack = vec![start];
+    while let Some(&(x, y)) = stack.last() {
+        if (x, y) == end {
+            break;
+        }
+        let unvisited: Vec<(usize, usize)> = [(0, 2), (2, 0), (0, -2), (-2, 0)]
+            .iter()
+            .filter_map(|&(dx, dy)| {
+                let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+                (next.0 < side - 1 && next.1 < side - 1 && !visited[next]).then_some(next)
+            })
+            .collect();
+        match unvisited.choose(&mut rng) {
+            Some(&next) => {
+                visited[next] = true;
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    let mut track = Grid::new(side, side, '#');
+    for pair in stack.windows(2) {
+        let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+        track[((x0 + x1) / 2, (y0 + y1) / 2)] = '.';
+        track[pair[1]] = '.';
+    }
+    track[start] = 'S';
+    track[end] = 'E';
+    track.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Maze;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(101, 20);
+        assert_eq!(input, generate(101, 20));
+        let maze = Maze::from_str(&input).unwrap();
+        assert!(maze.no_cheat_length().unwrap() > 100);
+        assert!(Maze::from_str(&generate(0, 20))
+            .unwrap()
+            .no_cheat_length()
+            .is_ok());
+    }
+}
@@ -0,0 +1,164 @@
+pub mod generator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{PuzzleError, Solution};
+use generic_search::bidirectional_bfs;
+use grid::Grid;
+use std::str::FromStr;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn title(&self) -> &'static str {
+        "Race Condition"
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Some(generator::generate(size, seed))
+    }
+
+    fn parse(&self, input: &str) -> Option<Result<()>> {
+        Some(Maze::from_str(input).map(|_| ()))
+    }
+
+    fn part_1(&self, input: &str) -> Result<String> {
+        Ok(exercise_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str) -> Result<String> {
+        Ok(exercise_2(input)?.to_string())
+    }
+}
+
+pub fn exercise_1(input_str: &str) -> Result<usize> {
+    let maze = Maze::from_str(input_str)?;
+    let length = maze.no_cheat_length()?;
+    Err(PuzzleError::unsolvable(format!(
+        "Counting cheats is not implemented yet (the race takes {} picoseconds without cheating)",
+        length
+    ))
+    .into())
+}
+
+pub fn exercise_2(input_str: &str) -> Result<usize> {
+    exercise_1(input_str)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Cell {
+    Empty,
+    Blocked,
+}
+
+struct Maze {
+    cells: Grid<Cell>,
+    start: (usize, usize),
+    goal: (usize, usize),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, std::hash::Hash)]
+struct State {
+    x: usize,
+    y: usize,
+}
+
+impl FromStr for Maze {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut start = None;
+        let mut goal = None;
+
+        let cells = Grid::parse_with(s, |(x, y), cell| match cell {
+            'S' => {
+                if start.is_some() {
+                    return Err(anyhow!("Multiple starts"));
+                }
+                start = Some((x, y));
+                Ok(Cell::Empty)
+            }
+            'E' => {
+                if goal.is_some() {
+                    return Err(anyhow!("Multiple goals"));
+                }
+                goal = Some((x, y));
+                Ok(Cell::Empty)
+            }
+            '.' => Ok(Cell::Empty),
+            '#' => Ok(Cell::Blocked),
+            _ => Err(anyhow!("Invalid cell: {}", cell)),
+        })?;
+
+        Ok(Self {
+            cells,
+            start: start.ok_or_else(|| PuzzleError::unsolvable("No start cell"))?,
+            goal: goal.ok_or_else(|| PuzzleError::unsolvable("No goal cell"))?,
+        })
+    }
+}
+
+impl Maze {
+    fn successors(&self, state: &State) -> Vec<State> {
+        self.cells
+            .neighbors4((state.x, state.y))
+            .filter(|&position| self.cells[position] == Cell::Empty)
+            .map(|(x, y)| State { x, y })
+            .collect()
+    }
+
+    fn no_cheat_length(&self) -> Result<usize> {
+        // Moves are reversible, so the successors are also the predecessors.
+        let successors = |state: &State| self.successors(state);
+        bidirectional_bfs(
+            State {
+                x: self.start.0,
+                y: self.start.1,
+            },
+            State {
+                x: self.goal.0,
+                y: self.goal.1,
+            },
+            successors,
+            successors,
+        )
+        .map(|path| path.len() - 1)
+        .ok_or_else(|| PuzzleError::unsolvable("No path found").into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[fixture]
+    fn maze() -> Maze {
+        Maze::from_str(
+            "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############",
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_no_cheat_length(maze: Maze) {
+        assert_eq!(maze.no_cheat_length().unwrap(), 84);
+    }
+}
@@ -0,0 +1,216 @@
+use anyhow::{ensure, Context, Result};
+use aoc_common::{Part, Solution};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// What is being timed: the parser alone, or a whole part (parsing included).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// Time budgets in milliseconds, read from a TOML file such as:
+///
+/// ```toml
+/// default = 1000
+///
+/// [days]
+/// 17 = 60000
+/// ```
+///
+/// Each phase of a day must run within the budget of that day, or `default` if it has none.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Budgets {
+    default: Option<u64>,
+    #[serde(default)]
+    days: HashMap<String, u64>,
+}
+
+impl Budgets {
+    pub fn load(path: &Path) -> Result<Self> {
+        let s = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&s).with_context(|| format!("Invalid {}", path.display()))
+    }
+
+    /// Applies `default` to every day without a budget of its own.
+    pub fn with_default(mut self, default: Option<u64>) -> Self {
+        self.default = default.or(self.default);
+        self
+    }
+
+    pub fn for_day(&self, day: u8) -> Option<Duration> {
+        self.days
+            .get(&day.to_string())
+            .or(self.default.as_ref())
+            .map(|&ms| Duration::from_millis(ms))
+    }
+}
+
+/// Timings of one phase of one day over several runs.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub phase: String,
+    pub runs: usize,
+    pub min_ms: f64,
+    pub mean_ms: f64,
+    pub max_ms: f64,
+    pub budget_ms: Option<u64>,
+    pub over_budget: bool,
+}
+
+impl Record {
+    fn new(day: u8, phase: Phase, samples: &[Duration], budget: Option<Duration>) -> Self {
+        let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+        Self {
+            day,
+            phase: phase.to_string(),
+            runs: samples.len(),
+            min_ms: millis(*samples.iter().min().unwrap()),
+            mean_ms: millis(mean),
+            max_ms: millis(*samples.iter().max().unwrap()),
+            budget_ms: budget.map(|b| b.as_millis() as u64),
+            over_budget: budget.is_some_and(|b| mean > b),
+        }
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1_000.0
+}
+
+/// Runs `f` `runs` times, failing on the first error.
+fn measure<F>(runs: usize, f: F) -> Result<Vec<Duration>>
+where
+    F: Fn() -> Result<()>,
+{
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            f()?;
+            Ok(start.elapsed())
+        })
+        .collect()
+}
+
+/// Times the parser, if the day has one, and both parts of `solution` on `input`.
+pub fn bench_day(
+    solution: &dyn Solution,
+    input: &str,
+    runs: usize,
+    budgets: &Budgets,
+) -> Result<Vec<Record>> {
+    ensure!(runs > 0, "At least one run is needed");
+    let day = solution.day();
+    let budget = budgets.for_day(day);
+
+    let mut records = vec![];
+    if solution.parse(input).is_some() {
+        let samples = measure(runs, || solution.parse(input).unwrap())
+            .with_context(|| format!("Day {} failed to parse its input", day))?;
+        records.push(Record::new(day, Phase::Parse, &samples, budget));
+    }
+    for part in Part::ALL {
+        let samples = measure(runs, || solution.solve(part, input).map(|_| ()))
+            .with_context(|| format!("Day {} part {} failed", day, part))?;
+        records.push(Record::new(day, Phase::Part(part), &samples, budget));
+    }
+    Ok(records)
+}
+
+/// Renders the records as a table, one line per phase.
+pub fn table(records: &[Record]) -> String {
+    let mut table = format!(
+        "{:<4} {:<7} {:>4} {:>12} {:>12} {:>12} {:>10}\n",
+        "Day", "Phase", "Runs", "Min (ms)", "Mean (ms)", "Max (ms)", "Budget"
+    );
+    for r in records {
+        let budget = match r.budget_ms {
+            Some(ms) if r.over_budget => format!("OVER {}", ms),
+            Some(ms) => ms.to_string(),
+            None => "-".to_string(),
+        };
+        table.push_str(&format!(
+            "{:<4} {:<7} {:>4} {:>12.3} {:>12.3} {:>12.3} {:>10}\n",
+            format!("{:02}", r.day),
+            r.phase,
+            r.runs,
+            r.min_ms,
+            r.mean_ms,
+            r.max_ms,
+            budget
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Slow;
+
+    impl Solution for Slow {
+        fn day(&self) -> u8 {
+            4
+        }
+
+        fn title(&self) -> &'static str {
+            "Slow"
+        }
+
+        fn parse(&self, _: &str) -> Option<Result<()>> {
+            Some(Ok(()))
+        }
+
+        fn part_1(&self, _: &str) -> Result<String> {
+            Ok("1".to_string())
+        }
+
+        fn part_2(&self, _: &str) -> Result<String> {
+            std::thread::sleep(Duration::from_millis(20));
+            Ok("2".to_string())
+        }
+    }
+
+    #[test]
+    fn test_budgets() {
+        let budgets: Budgets = toml::from_str("default = 10\n[days]\n4 = 100\n").unwrap();
+        assert_eq!(budgets.for_day(4), Some(Duration::from_millis(100)));
+        assert_eq!(budgets.for_day(5), Some(Duration::from_millis(10)));
+        let budgets = Budgets::default().with_default(Some(5));
+        assert_eq!(budgets.for_day(4), Some(Duration::from_millis(5)));
+        assert_eq!(Budgets::default().for_day(4), None);
+    }
+
+    #[test]
+    fn test_bench_day_flags_phases_over_budget() {
+        let budgets = Budgets::default().with_default(Some(10));
+        let records = bench_day(&Slow, "", 2, &budgets).unwrap();
+        let phases: Vec<_> = records.iter().map(|r| r.phase.as_str()).collect();
+        assert_eq!(phases, ["parse", "part 1", "part 2"]);
+        assert!(records.iter().all(|r| r.runs == 2));
+        let over: Vec<_> = records.iter().map(|r| r.over_budget).collect();
+        assert_eq!(over, [false, false, true]);
+        assert!(records[2].min_ms >= 20.0);
+        assert!(table(&records).contains("OVER 10"));
+    }
+}
@@ -0,0 +1,231 @@
+use anyhow::{anyhow, ensure, Context, Result};
+use aoc_common::{verify_dir, Answer, Format, Part, Solution, Verdict};
+use bench::Budgets;
+use clap::{Parser, Subcommand};
+use std::{
+    fs::{self, File},
+    io::BufReader,
+    path::{Path, PathBuf},
+};
+
+mod bench;
+mod solutions;
+
+/// Run any day of the Advent of Code 2024 from one place.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or both parts of a day.
+    Run {
+        /// Day to run (1-25).
+        day: u8,
+        /// Only run this part (1 or 2).
+        #[arg(short, long)]
+        part: Option<Part>,
+        /// Puzzle input, defaults to `<DD>/input.txt`.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// `text`, or `json` for one `{day, part, answer, duration}` record per line.
+        #[arg(short, long, default_value = "text")]
+        format: Format,
+        /// Read the input one record at a time instead of loading it whole, for inputs too large
+        /// to fit in memory. Only some days support it.
+        #[arg(long)]
+        stream: bool,
+    },
+    /// Check the answers of one or every day against their `answers.toml`.
+    Verify {
+        /// Only verify this day (1-25).
+        day: Option<u8>,
+    },
+    /// Time the parser and both parts of one or every day.
+    Bench {
+        /// Only benchmark this day (1-25).
+        day: Option<u8>,
+        /// Number of runs of each phase.
+        #[arg(short, long, default_value_t = 3)]
+        runs: usize,
+        /// TOML file with the time budgets, in milliseconds, of every day.
+        #[arg(long)]
+        budgets: Option<PathBuf>,
+        /// Budget in milliseconds for any phase of a day without its own budget.
+        #[arg(long)]
+        budget_ms: Option<u64>,
+        /// Also write the timings as JSON to this file.
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Time a generated input of this size instead of `input.txt`.
+        #[arg(long)]
+        size: Option<usize>,
+        /// Seed of the generated input.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Print a random input for a day, to stress-test or benchmark it at scale.
+    Generate {
+        /// Day to generate an input for (1-25).
+        day: u8,
+        /// Scale of the input, whose meaning depends on the day (lines, grid side, ...).
+        #[arg(short, long)]
+        size: usize,
+        /// Seed of the random generator, the same seed always giving the same input.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// List every available day.
+    List,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            stream,
+        } => {
+            let solution = find(day)?;
+            let input_path = input.unwrap_or_else(|| default_input_path(day));
+            let read_error = || format!("Failed to read {}", input_path.display());
+            let input_str = if stream {
+                String::new()
+            } else {
+                fs::read_to_string(&input_path).with_context(read_error)?
+            };
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => Part::ALL.to_vec(),
+            };
+            for part in parts {
+                let answer = if stream {
+                    let mut reader =
+                        BufReader::new(File::open(&input_path).with_context(read_error)?);
+                    Answer::solve_reader(solution, part, &mut reader)?
+                } else {
+                    Answer::solve(solution, part, &input_str)?
+                };
+                println!("{}", answer.render(format));
+            }
+        }
+        Command::Verify { day } => {
+            let solutions = select(day)?;
+            verify(&solutions)?;
+        }
+        Command::Bench {
+            day,
+            runs,
+            budgets,
+            budget_ms,
+            json,
+            size,
+            seed,
+        } => {
+            let budgets = match budgets {
+                Some(path) => Budgets::load(&path)?,
+                None => Budgets::default(),
+            }
+            .with_default(budget_ms);
+            let solutions = select(day)?;
+            let generated = size.map(|size| (size, seed));
+            bench(&solutions, runs, &budgets, json.as_deref(), generated)?;
+        }
+        Command::Generate { day, size, seed } => {
+            print!("{}", generate(find(day)?, size, seed)?);
+        }
+        Command::List => {
+            for solution in solutions::all() {
+                println!("{:02}: {}", solution.day(), solution.title());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The given day, or every day if `None`.
+fn select(day: Option<u8>) -> Result<Vec<&'static dyn Solution>> {
+    match day {
+        Some(day) => Ok(vec![find(day)?]),
+        None => Ok(solutions::all().collect()),
+    }
+}
+
+fn find(day: u8) -> Result<&'static dyn Solution> {
+    solutions::find(day).ok_or_else(|| anyhow!("Day {} is not solved", day))
+}
+
+fn day_dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("{:02}", day))
+}
+
+fn default_input_path(day: u8) -> PathBuf {
+    day_dir(day).join("input.txt")
+}
+
+fn verify(solutions: &[&dyn Solution]) -> Result<()> {
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    for &solution in solutions {
+        for check in verify_dir(solution, &day_dir(solution.day()))? {
+            println!("{}", check);
+            match check.verdict() {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail => failed += 1,
+                Verdict::Unknown => unknown += 1,
+            }
+        }
+    }
+    println!("{} passed, {} failed, {} unknown", passed, failed, unknown);
+    ensure!(
+        failed == 0,
+        "{} part(s) did not match their accepted answer",
+        failed
+    );
+    Ok(())
+}
+
+fn generate(solution: &dyn Solution, size: usize, seed: u64) -> Result<String> {
+    solution
+        .generate(size, seed)
+        .ok_or_else(|| anyhow!("Day {} has no input generator", solution.day()))
+}
+
+/// Times every solution on its `input.txt`, or on an input generated with the given size and
+/// seed.
+fn bench(
+    solutions: &[&dyn Solution],
+    runs: usize,
+    budgets: &Budgets,
+    json: Option<&Path>,
+    generated: Option<(usize, u64)>,
+) -> Result<()> {
+    let mut records = vec![];
+    for &solution in solutions {
+        let input_str = match generated {
+            Some((size, seed)) => generate(solution, size, seed)?,
+            None => {
+                let input_path = default_input_path(solution.day());
+                fs::read_to_string(&input_path)
+                    .with_context(|| format!("Failed to read {}", input_path.display()))?
+            }
+        };
+        records.extend(bench::bench_day(solution, &input_str, runs, budgets)?);
+    }
+    print!("{}", bench::table(&records));
+    if let Some(path) = json {
+        fs::write(path, serde_json::to_string_pretty(&records)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    let over = records.iter().filter(|r| r.over_budget).count();
+    ensure!(over == 0, "{} phase(s) went over their budget", over);
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use aoc_common::Solution;
+
+static SOLUTIONS: [&(dyn Solution + Sync); 20] = [
+    &historian_hysteria::Day01,
+    &red_nosed_reports::Day02,
+    &mull_it_over::Day03,
+    &ceres_search::Day04,
+    &print_queue::Day05,
+    &guard_gallivant::Day06,
+    &bridge_repair::Day07,
+    &resonant_colinearity::Day08,
+    &disk_fragmenter::Day09,
+    &hoof_it::Day10,
+    &plutonian_pebbles::Day11,
+    &garden_groups::Day12,
+    &claw_contraption::Day13,
+    &restroom_redoubt::Day14,
+    &warehouse_woes::Day15,
+    &reindeer_maze::Day16,
+    &chronospatial_computer::Day17,
+    &ram_run::Day18,
+    &linen_layout::Day19,
+    &race_condition::Day20,
+];
+
+pub fn all() -> impl Iterator<Item = &'static dyn Solution> {
+    SOLUTIONS.iter().map(|&s| s as &dyn Solution)
+}
+
+pub fn find(day: u8) -> Option<&'static dyn Solution> {
+    all().find(|s| s.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Part;
+    use proptest::prelude::*;
+    use std::{sync::mpsc, thread, time::Duration};
+
+    /// Longest a part may take on a small edited input before it is considered to hang.
+    const SOLVE_TIMEOUT: Duration = Duration::from_secs(10);
+
+    #[test]
+    fn test_days_are_registered_in_order() {
+        let days: Vec<u8> = all().map(|s| s.day()).collect();
+        assert_eq!(days, (1..=20).collect::<Vec<u8>>());
+    }
+
+    /// Edits applied to a generated input: a character position, and what to put there, or a whole
+    /// line to drop.
+    #[derive(Debug, Clone)]
+    enum Edit {
+        Insert(usize, char),
+        Replace(usize, char),
+        Remove(usize),
+        RemoveLine(usize),
+    }
+
+    fn apply(input: &str, edits: &[Edit]) -> String {
+        let mut chars: Vec<char> = input.chars().collect();
+        for edit in edits {
+            let len = chars.len();
+            match *edit {
+                Edit::Insert(i, c) => chars.insert(i % (len + 1), c),
+                Edit::Replace(i, c) if len > 0 => chars[i % len] = c,
+                Edit::Remove(i) if len > 0 => {
+                    chars.remove(i % len);
+                }
+                Edit::RemoveLine(i) => {
+                    let text: String = chars.iter().collect();
+                    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+                    if !lines.is_empty() {
+                        let removed = i % lines.len();
+                        chars = lines
+                            .iter()
+                            .enumerate()
+                            .filter(|&(j, _)| j != removed)
+                            .flat_map(|(_, line)| line.chars())
+                            .collect();
+                    }
+                }
+                _ => {}
+            }
+        }
+        chars.into_iter().collect()
+    }
+
+    /// The characters of the puzzle inputs, and a few that never appear in them.
+    fn puzzle_char() -> impl Strategy<Value = char> {
+        prop_oneof![
+            prop::sample::select(
+                "0123456789 ,|:-+=\n#.SE@O^<>vXMA"
+                    .chars()
+                    .collect::<Vec<_>>()
+            ),
+            any::<char>(),
+        ]
+    }
+
+    fn edit() -> impl Strategy<Value = Edit> {
+        prop_oneof![
+            (any::<usize>(), puzzle_char()).prop_map(|(i, c)| Edit::Insert(i, c)),
+            (any::<usize>(), puzzle_char()).prop_map(|(i, c)| Edit::Replace(i, c)),
+            any::<usize>().prop_map(Edit::Remove),
+            any::<usize>().prop_map(Edit::RemoveLine),
+        ]
+    }
+
+    /// Runs both parts of `solution` on `input` in another thread, failing if one panics or does
+    /// not return within [`SOLVE_TIMEOUT`]. Errors are fine, bad input is expected to cause them.
+    fn solve_in_time(
+        solution: &'static (dyn Solution + Sync),
+        input: String,
+    ) -> Result<(), String> {
+        for part in Part::ALL {
+            let (sender, receiver) = mpsc::channel();
+            let input = input.clone();
+            thread::spawn(move || {
+                let _ = solution.solve(part, &input);
+                let _ = sender.send(());
+            });
+            match receiver.recv_timeout(SOLVE_TIMEOUT) {
+                Ok(()) => {}
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    return Err(format!("Day {} part {} hangs", solution.day(), part))
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    return Err(format!("Day {} part {} panics", solution.day(), part))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn prop_parsers_reject_random_text(input in prop::collection::vec(puzzle_char(), 0..200)) {
+            let input: String = input.into_iter().collect();
+            for solution in all() {
+                let _ = solution.parse(&input);
+            }
+        }
+
+        /// Small generated inputs with a few edits get much further into the parsers.
+        #[test]
+        fn prop_parsers_reject_edited_inputs(
+            size in 0..6usize,
+            seed in any::<u64>(),
+            edits in prop::collection::vec(edit(), 0..4),
+        ) {
+            for solution in all() {
+                if let Some(input) = solution.generate(size, seed) {
+                    let _ = solution.parse(&apply(&input, &edits));
+                }
+            }
+        }
+    }
+
+    proptest! {
+        // Every case runs all the solvers, which takes much longer than parsing.
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        /// The solvers also have to fail cleanly on edited inputs, rather than panic or hang.
+        #[test]
+        fn prop_solvers_reject_edited_inputs(
+            size in 0..6usize,
+            seed in any::<u64>(),
+            edits in prop::collection::vec(edit(), 0..4),
+        ) {
+            for &solution in &SOLUTIONS {
+                if let Some(input) = solution.generate(size, seed) {
+                    let result = solve_in_time(solution, apply(&input, &edits));
+                    prop_assert!(result.is_ok(), "{}", result.unwrap_err());
+                }
+            }
+        }
+    }
+}
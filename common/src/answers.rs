@@ -0,0 +1,207 @@
+use crate::{Part, Solution};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+};
+
+/// Accepted answers for the real `input.txt` of a day, stored next to it in `answers.toml`:
+///
+/// ```toml
+/// part_1 = "4752"
+/// part_2 = "1719"
+/// ```
+///
+/// A part without an entry has not been accepted yet.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    part_1: Option<String>,
+    part_2: Option<String>,
+}
+
+impl Answers {
+    pub const FILE_NAME: &'static str = "answers.toml";
+
+    /// Reads `answers.toml` from `dir`. A missing file means no answer is known yet.
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(Self::FILE_NAME);
+        match fs::read_to_string(&path) {
+            Ok(s) => s
+                .parse()
+                .with_context(|| format!("Invalid {}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        }
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_1.as_deref(),
+            Part::Two => self.part_2.as_deref(),
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(toml::from_str(s)?)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    /// The part ran but there is no accepted answer to compare with.
+    Unknown,
+}
+
+/// Outcome of running one part against its accepted answer.
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub expected: Option<String>,
+    /// The answer, or the error message if the part failed to run.
+    pub actual: Result<String, String>,
+}
+
+impl Check {
+    pub fn verdict(&self) -> Verdict {
+        match (&self.expected, &self.actual) {
+            (_, Err(_)) => Verdict::Fail,
+            (None, Ok(_)) => Verdict::Unknown,
+            (Some(expected), Ok(actual)) if expected == actual => Verdict::Pass,
+            (Some(_), Ok(_)) => Verdict::Fail,
+        }
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {:02} part {}: ", self.day, self.part)?;
+        match (self.verdict(), &self.expected, &self.actual) {
+            (_, _, Err(error)) => write!(f, "FAIL ({})", error),
+            (Verdict::Pass, _, Ok(actual)) => write!(f, "PASS ({})", actual),
+            (Verdict::Unknown, _, Ok(actual)) => {
+                write!(f, "UNKNOWN ({}, not in {})", actual, Answers::FILE_NAME)
+            }
+            (_, expected, Ok(actual)) => write!(
+                f,
+                "FAIL (expected {}, got {})",
+                expected.as_deref().unwrap_or_default(),
+                actual
+            ),
+        }
+    }
+}
+
+/// Runs both parts of `solution` on `input` and compares them with `answers`.
+pub fn verify(solution: &dyn Solution, input: &str, answers: &Answers) -> Vec<Check> {
+    Part::ALL
+        .into_iter()
+        .map(|part| Check {
+            day: solution.day(),
+            part,
+            expected: answers.expected(part).map(str::to_string),
+            actual: solution
+                .solve(part, input)
+                .map_err(|error| format!("{:#}", error)),
+        })
+        .collect()
+}
+
+/// [`verify`] with the `input.txt` and `answers.toml` found in `dir`.
+pub fn verify_dir(solution: &dyn Solution, dir: &Path) -> Result<Vec<Check>> {
+    let input_path = dir.join("input.txt");
+    let input = fs::read_to_string(&input_path)
+        .with_context(|| format!("Failed to read {}", input_path.display()))?;
+    let answers = Answers::load(dir)?;
+    Ok(verify(solution, &input, &answers))
+}
+
+/// Entry point of the `verify` mode of the day binaries, run from the day's directory.
+///
+/// Prints one line per part and exits with a non-zero status if any part failed.
+pub fn verify_and_exit(solution: &dyn Solution) -> ! {
+    match verify_dir(solution, &PathBuf::from(".")) {
+        Ok(checks) => {
+            for check in &checks {
+                println!("{}", check);
+            }
+            let failed = checks.iter().any(|c| c.verdict() == Verdict::Fail);
+            process::exit(i32::from(failed))
+        }
+        Err(error) => {
+            eprintln!("Error: {:#}", error);
+            process::exit(1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::bail;
+
+    struct Fake;
+
+    impl Solution for Fake {
+        fn day(&self) -> u8 {
+            3
+        }
+
+        fn title(&self) -> &'static str {
+            "Fake"
+        }
+
+        fn part_1(&self, input: &str) -> Result<String> {
+            Ok(input.len().to_string())
+        }
+
+        fn part_2(&self, _: &str) -> Result<String> {
+            bail!("Not solved")
+        }
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let answers: Answers = "part_1 = \"42\"\n".parse().unwrap();
+        assert_eq!(answers.expected(Part::One), Some("42"));
+        assert_eq!(answers.expected(Part::Two), None);
+        assert!("part_3 = \"1\"".parse::<Answers>().is_err());
+        assert!("part_1 = 42".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_verdicts() {
+        let pass = verify(&Fake, "abc", &"part_1 = \"3\"".parse().unwrap());
+        assert_eq!(pass[0].verdict(), Verdict::Pass);
+        assert_eq!(pass[0].to_string(), "Day 03 part 1: PASS (3)");
+        assert_eq!(pass[1].verdict(), Verdict::Fail);
+        assert_eq!(pass[1].to_string(), "Day 03 part 2: FAIL (Not solved)");
+
+        let fail = verify(&Fake, "abcd", &"part_1 = \"3\"".parse().unwrap());
+        assert_eq!(fail[0].verdict(), Verdict::Fail);
+        assert_eq!(
+            fail[0].to_string(),
+            "Day 03 part 1: FAIL (expected 3, got 4)"
+        );
+
+        let unknown = verify(&Fake, "abc", &Answers::default());
+        assert_eq!(unknown[0].verdict(), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_missing_answers_file() {
+        let answers = Answers::load(Path::new("does/not/exist")).unwrap();
+        assert_eq!(answers, Answers::default());
+    }
+}
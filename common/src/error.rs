@@ -0,0 +1,214 @@
+use std::{fmt::Display, io};
+use thiserror::Error;
+
+/// What can go wrong while reading a puzzle input or solving it.
+///
+/// Parsers and solvers report through this type so that every day fails the same way on bad
+/// input, instead of panicking or silently skipping lines.
+#[derive(Debug, Error)]
+pub enum PuzzleError {
+    #[error("Failed to read the input: {0}")]
+    Io(#[from] io::Error),
+
+    /// `snippet` is the offending line, printed with a caret under the `span`:
+    ///
+    /// ```text
+    /// Line 2, column 3: Invalid cell: x
+    /// 2 | ..x#
+    ///   |   ^
+    /// ```
+    #[error("Line {}, column {}: {message}\n{}", .span.line, .span.column, caret(.span, .snippet))]
+    Malformed {
+        span: Span,
+        snippet: String,
+        message: String,
+    },
+
+    #[error("{what} should be {expected}, found {found}")]
+    DimensionMismatch {
+        what: String,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("Unsolvable input: {0}")]
+    Unsolvable(String),
+}
+
+/// Where a malformed piece of input is: `line` and `column` are 1-based and, like `width`, count
+/// characters rather than bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub width: usize,
+}
+
+/// The snippet under its line number, and the span underlined on the next line.
+fn caret(span: &Span, snippet: &str) -> String {
+    let number = span.line.to_string();
+    // Tabs are kept so that the caret lines up however wide they are displayed.
+    let padding: String = snippet
+        .chars()
+        .take(span.column - 1)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    format!(
+        "{} | {}\n{} | {}{}",
+        number,
+        snippet,
+        " ".repeat(number.len()),
+        padding,
+        "^".repeat(span.width.max(1))
+    )
+}
+
+pub type PuzzleResult<T> = Result<T, PuzzleError>;
+
+impl PuzzleError {
+    /// A malformed `line`, the `index`-th of the input (0-based), at byte `offset` into the line.
+    pub fn malformed(index: usize, offset: usize, line: &str, message: impl Display) -> Self {
+        Self::malformed_span(index, offset, 1, line, message)
+    }
+
+    /// Like [`PuzzleError::malformed`], underlining the `width` characters from `offset` on.
+    pub fn malformed_span(
+        index: usize,
+        offset: usize,
+        width: usize,
+        line: &str,
+        message: impl Display,
+    ) -> Self {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let column = match line.get(..offset) {
+            Some(prefix) => prefix.chars().count(),
+            None => offset,
+        };
+        let remaining = line.chars().count().saturating_sub(column);
+        Self::Malformed {
+            span: Span {
+                line: index + 1,
+                column: column + 1,
+                width: width.min(remaining).max(1),
+            },
+            snippet: line.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// A malformed `token`, which must be a slice of `line`.
+    pub fn malformed_token(index: usize, line: &str, token: &str, message: impl Display) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|&offset| offset <= line.len())
+            .unwrap_or(0);
+        Self::malformed_span(index, offset, token.chars().count(), line, message)
+    }
+
+    /// A malformed `token`, which must be a slice of the whole `input`.
+    pub fn malformed_slice(input: &str, token: &str, message: impl Display) -> Self {
+        let offset = (token.as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+        Self::locate(input, offset, token.chars().count(), message)
+    }
+
+    /// A malformed `input` at byte `offset` into the whole input.
+    pub fn malformed_at(input: &str, offset: usize, message: impl Display) -> Self {
+        Self::locate(input, offset, 1, message)
+    }
+
+    fn locate(input: &str, offset: usize, width: usize, message: impl Display) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let index = input[..start].matches('\n').count();
+        Self::malformed_span(index, offset - start, width, &input[start..end], message)
+    }
+
+    /// Where the input is malformed, if that is the problem.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Self::Malformed { span, .. } => Some(*span),
+            _ => None,
+        }
+    }
+
+    pub fn dimension_mismatch(what: impl Display, expected: usize, found: usize) -> Self {
+        Self::DimensionMismatch {
+            what: what.to_string(),
+            expected,
+            found,
+        }
+    }
+
+    pub fn unsolvable(message: impl Display) -> Self {
+        Self::Unsolvable(message.to_string())
+    }
+}
+
+/// Turns the error of parsing a whole line into a [`PuzzleError::Malformed`] underlining it.
+pub trait LineContext<T> {
+    /// `index` is the 0-based position of `line` in the input.
+    fn on_line(self, index: usize, line: &str) -> PuzzleResult<T>;
+}
+
+impl<T, E: Display> LineContext<T> for Result<T, E> {
+    fn on_line(self, index: usize, line: &str) -> PuzzleResult<T> {
+        self.map_err(|error| {
+            PuzzleError::malformed_token(index, line, line, format!("{:#}", error))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_positions() {
+        let line = "12 x4 7";
+        let error = PuzzleError::malformed_token(2, line, &line[3..5], "Not a number");
+        assert_eq!(
+            error.to_string(),
+            "Line 3, column 4: Not a number\n3 | 12 x4 7\n  |    ^^"
+        );
+
+        let error = PuzzleError::malformed_at("1|2\n3-4\n", 5, "Expected `|`");
+        assert_eq!(
+            error.to_string(),
+            "Line 2, column 2: Expected `|`\n2 | 3-4\n  |  ^"
+        );
+
+        let error = "é!".parse::<u8>().on_line(0, "é!");
+        assert_eq!(
+            error.unwrap_err().span(),
+            Some(Span {
+                line: 1,
+                column: 1,
+                width: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_caret_alignment() {
+        let input = "a\nb\nc\nd\ne\nf\ng\nh\ni\n\té,x;z\r\n";
+        let error = PuzzleError::malformed_slice(input, &input[22..], "Unexpected end");
+        assert_eq!(
+            error.to_string(),
+            "Line 10, column 4: Unexpected end\n10 | \té,x;z\n   | \t  ^^^"
+        );
+    }
+
+    #[test]
+    fn test_other_variants() {
+        let error = PuzzleError::dimension_mismatch("Length of row 2", 3, 4);
+        assert_eq!(error.to_string(), "Length of row 2 should be 3, found 4");
+        let error = PuzzleError::unsolvable("No path to the exit");
+        assert_eq!(error.to_string(), "Unsolvable input: No path to the exit");
+    }
+}
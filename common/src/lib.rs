@@ -0,0 +1,20 @@
+//! Shared building blocks for the Advent of Code 2024 solutions.
+//!
+//! Every day exposes a type implementing [`Solution`] so that the `aoc` runner
+//! can dispatch to it without knowing anything about the puzzle itself. The
+//! accepted answers for the real inputs live in each day's `answers.toml`, see
+//! [`Answers`]. Answers are printed as text or JSON records, see [`Format`], and
+//! bad inputs are reported as a [`PuzzleError`] pointing at the offending
+//! characters, which a [`Scanner`] keeps track of while parsing.
+
+mod answers;
+mod error;
+mod output;
+mod scanner;
+mod solution;
+
+pub use answers::{verify, verify_and_exit, verify_dir, Answers, Check, Verdict};
+pub use error::{LineContext, PuzzleError, PuzzleResult, Span};
+pub use output::{run_and_exit, Answer, Format};
+pub use scanner::Scanner;
+pub use solution::{Part, Solution};
@@ -0,0 +1,180 @@
+use crate::{answers::verify_and_exit, Part, Solution};
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Serialize;
+use std::{fs, io::BufRead, process, str::FromStr, time::Instant};
+
+/// How answers are written to stdout.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One `Day 06 part 1: 41` line per part.
+    #[default]
+    Text,
+    /// One JSON record per line, see [`Answer`].
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(anyhow!("Invalid format: {} (expected text or json)", s)),
+        }
+    }
+}
+
+/// The answer to one part, with the time it took to compute it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// In seconds.
+    pub duration: f64,
+}
+
+impl Answer {
+    /// Solves `part` of `solution`, timing it.
+    pub fn solve(solution: &dyn Solution, part: Part, input: &str) -> Result<Self> {
+        Self::timed(solution, part, || solution.solve(part, input))
+    }
+
+    /// Like [`Answer::solve`], reading the input incrementally with [`Solution::solve_reader`].
+    pub fn solve_reader(
+        solution: &dyn Solution,
+        part: Part,
+        input: &mut dyn BufRead,
+    ) -> Result<Self> {
+        Self::timed(solution, part, || {
+            solution.solve_reader(part, input).unwrap_or_else(|| {
+                Err(anyhow!(
+                    "Day {} cannot read its input incrementally",
+                    solution.day()
+                ))
+            })
+        })
+    }
+
+    fn timed<F>(solution: &dyn Solution, part: Part, f: F) -> Result<Self>
+    where
+        F: FnOnce() -> Result<String>,
+    {
+        let start = Instant::now();
+        let answer = f().with_context(|| format!("Day {} part {} failed", solution.day(), part))?;
+        Ok(Self {
+            day: solution.day(),
+            part: part.number(),
+            answer,
+            duration: start.elapsed().as_secs_f64(),
+        })
+    }
+
+    /// The line to print for this answer, without the trailing newline.
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Text => format!("Day {:02} part {}: {}", self.day, self.part, self.answer),
+            Format::Json => serde_json::to_string(self).expect("Answers serialize to JSON"),
+        }
+    }
+}
+
+/// Entry point of the day binaries, run from the day's directory.
+///
+/// Solves both parts on `input.txt` and prints them in the format given by `--format text|json`,
+/// or checks them against `answers.toml` when the first argument is `verify`. Any error goes to
+/// stderr with a non-zero exit status, so that stdout only ever holds answers.
+pub fn run_and_exit(solution: &dyn Solution) -> ! {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("verify") {
+        verify_and_exit(solution);
+    }
+    let result = parse_format(&args).and_then(|format| {
+        let input = fs::read_to_string("input.txt").context("Failed to read input.txt")?;
+        for part in Part::ALL {
+            println!("{}", Answer::solve(solution, part, &input)?.render(format));
+        }
+        Ok(())
+    });
+    if let Err(error) = result {
+        eprintln!("Error: {:#}", error);
+        process::exit(1);
+    }
+    process::exit(0)
+}
+
+fn parse_format(args: &[String]) -> Result<Format> {
+    match args {
+        [] => Ok(Format::default()),
+        [flag, format] if flag == "--format" => format.parse(),
+        [flag] => match flag.strip_prefix("--format=") {
+            Some(format) => format.parse(),
+            None => bail!("Usage: [verify | --format text|json]"),
+        },
+        _ => bail!("Usage: [verify | --format text|json]"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fake;
+
+    impl Solution for Fake {
+        fn day(&self) -> u8 {
+            6
+        }
+
+        fn title(&self) -> &'static str {
+            "Fake"
+        }
+
+        fn solve_reader(&self, part: Part, input: &mut dyn BufRead) -> Option<Result<String>> {
+            let mut s = String::new();
+            Some(match part {
+                Part::One => input
+                    .read_to_string(&mut s)
+                    .map(|_| s.len().to_string())
+                    .map_err(Into::into),
+                Part::Two => self.part_2(""),
+            })
+        }
+
+        fn part_1(&self, input: &str) -> Result<String> {
+            Ok(input.len().to_string())
+        }
+
+        fn part_2(&self, _: &str) -> Result<String> {
+            bail!("Not solved")
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let mut answer = Answer::solve(&Fake, Part::One, "abc").unwrap();
+        assert_eq!(answer.render(Format::Text), "Day 06 part 1: 3");
+        answer.duration = 0.25;
+        assert_eq!(
+            answer.render(Format::Json),
+            r#"{"day":6,"part":1,"answer":"3","duration":0.25}"#
+        );
+        assert!(Answer::solve(&Fake, Part::Two, "abc").is_err());
+
+        let answer = Answer::solve_reader(&Fake, Part::One, &mut "abcd".as_bytes()).unwrap();
+        assert_eq!(answer.answer, "4");
+        assert!(Answer::solve_reader(&Fake, Part::Two, &mut "abcd".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_parse_format() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+        assert_eq!(parse_format(&args("")).unwrap(), Format::Text);
+        assert_eq!(parse_format(&args("--format json")).unwrap(), Format::Json);
+        assert_eq!(parse_format(&args("--format=text")).unwrap(), Format::Text);
+        assert!(parse_format(&args("--format yaml")).is_err());
+        assert!(parse_format(&args("--format")).is_err());
+        assert!(parse_format(&args("json")).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{PuzzleError, PuzzleResult};
+use std::{fmt::Display, str::FromStr};
+
+/// Reads a structured piece of the input from left to right, keeping track of where it is so
+/// that errors point at the first character that does not fit.
+///
+/// ```
+/// use aoc_common::Scanner;
+///
+/// let input = "p=0,4 v=3,-3\np=6,3 v=-1,x\n";
+/// let line = input.lines().nth(1).unwrap();
+/// let mut scanner = Scanner::within(input, line);
+/// scanner.literal("p=").unwrap();
+/// assert_eq!(scanner.number::<usize>().unwrap(), 6);
+/// scanner.literal(",").unwrap();
+/// assert_eq!(scanner.number::<usize>().unwrap(), 3);
+/// scanner.literal(" v=").unwrap();
+/// assert_eq!(scanner.number::<isize>().unwrap(), -1);
+/// scanner.literal(",").unwrap();
+/// let error = scanner.number::<isize>().unwrap_err();
+/// assert!(error.to_string().starts_with("Line 2, column 12: Expected a number"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    input: &'a str,
+    offset: usize,
+    end: usize,
+    /// Lines of the whole input before `input`.
+    first_line: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self::on_line(0, input)
+    }
+
+    /// Scans `line`, the `index`-th line of an input (0-based) read one line at a time.
+    pub fn on_line(index: usize, line: &'a str) -> Self {
+        Self {
+            input: line,
+            offset: 0,
+            end: line.len(),
+            first_line: index,
+        }
+    }
+
+    /// Scans `part`, which must be a slice of `input`, reporting positions within `input`.
+    pub fn within(input: &'a str, part: &'a str) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + part.len() <= input.len())
+            .expect("The part is a slice of the input");
+        Self {
+            input,
+            offset,
+            end: offset + part.len(),
+            first_line: 0,
+        }
+    }
+
+    /// What is left to scan.
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.offset..self.end]
+    }
+
+    /// Whether only whitespace is left.
+    pub fn is_done(&self) -> bool {
+        self.rest().trim().is_empty()
+    }
+
+    /// A malformed input error at the current position.
+    pub fn error(&self, message: impl Display) -> PuzzleError {
+        self.shift(PuzzleError::malformed_at(self.input, self.offset, message))
+    }
+
+    fn shift(&self, mut error: PuzzleError) -> PuzzleError {
+        if let PuzzleError::Malformed { span, .. } = &mut error {
+            span.line += self.first_line;
+        }
+        error
+    }
+
+    /// Skips `expected`, or fails at the first character that differs from it.
+    pub fn literal(&mut self, expected: &str) -> PuzzleResult<()> {
+        let rest = self.rest();
+        if let Some(stripped) = rest.strip_prefix(expected) {
+            self.offset = self.end - stripped.len();
+            return Ok(());
+        }
+        let matching: usize = rest
+            .chars()
+            .zip(expected.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum();
+        self.offset += matching;
+        Err(self.error(format!("Expected {:?}", expected)))
+    }
+
+    /// Reads a decimal number, with an optional leading `-`.
+    pub fn number<T>(&mut self) -> PuzzleResult<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with('-'));
+        let length = sign
+            + rest[sign..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len() - sign);
+        if length == sign {
+            return Err(self.error("Expected a number"));
+        }
+        let token = &rest[..length];
+        let number = token
+            .parse()
+            .map_err(|e| self.shift(PuzzleError::malformed_slice(self.input, token, e)))?;
+        self.offset += length;
+        Ok(number)
+    }
+
+    /// Fails unless only whitespace is left.
+    pub fn finish(&mut self) -> PuzzleResult<()> {
+        if !self.is_done() {
+            let rest = self.rest();
+            self.offset += rest.len() - rest.trim_start().len();
+            return Err(self.error("Unexpected trailing characters"));
+        }
+        self.offset = self.end;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner() {
+        let mut scanner = Scanner::new("Button A: X+94, Y+34  \n");
+        scanner.literal("Button A: X+").unwrap();
+        assert_eq!(scanner.number::<usize>().unwrap(), 94);
+        scanner.literal(", Y+").unwrap();
+        assert_eq!(scanner.number::<usize>().unwrap(), 34);
+        assert!(scanner.is_done());
+        scanner.finish().unwrap();
+
+        let mut scanner = Scanner::new("Button B: Y+1");
+        let error = scanner.literal("Button B: X+").unwrap_err();
+        assert_eq!(error.span().unwrap().column, 11);
+
+        let mut scanner = Scanner::on_line(2, "-5 99999999999 7 x");
+        let error = scanner.number::<usize>().unwrap_err();
+        assert_eq!(error.span().unwrap().width, 2);
+        assert_eq!(scanner.number::<isize>().unwrap(), -5);
+        scanner.literal(" ").unwrap();
+        let error = scanner.number::<i32>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 3, column 4: number too large to fit in target type\n\
+             3 | -5 99999999999 7 x\n  \
+               |    ^^^^^^^^^^^"
+        );
+        scanner.number::<u64>().unwrap();
+        let error = scanner.finish().unwrap_err();
+        assert_eq!(error.span().unwrap().column, 16);
+    }
+}
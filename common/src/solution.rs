@@ -0,0 +1,95 @@
+use anyhow::{anyhow, Result};
+use std::{fmt::Display, io::BufRead, str::FromStr};
+
+/// One of the two halves of a daily puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(anyhow!("Invalid part: {} (expected 1 or 2)", s)),
+        }
+    }
+}
+
+/// Common interface implemented by every day.
+///
+/// Both parts receive the raw puzzle input and return the answer already
+/// formatted the way the Advent of Code website expects it.
+pub trait Solution {
+    /// Day of the advent calendar this solution belongs to.
+    fn day(&self) -> u8;
+
+    /// Title of the puzzle.
+    fn title(&self) -> &'static str;
+
+    /// Parses `input` the way the parts do, without solving anything, so that
+    /// parsing can be timed on its own. `None` for days whose parts parse the
+    /// input as they go.
+    fn parse(&self, _input: &str) -> Option<Result<()>> {
+        None
+    }
+
+    /// Solves `part` reading `input` one record at a time, so that inputs too
+    /// large to hold in memory can be solved. `None` for days that need the
+    /// whole input at once.
+    fn solve_reader(&self, _part: Part, _input: &mut dyn BufRead) -> Option<Result<String>> {
+        None
+    }
+
+    /// A random input of the given `size`, valid for both parts, to stress-test
+    /// and benchmark at scale. The same `seed` always gives the same input.
+    /// What `size` measures depends on the puzzle, see the day's `generator`
+    /// module.
+    fn generate(&self, _size: usize, _seed: u64) -> Option<String> {
+        None
+    }
+
+    fn part_1(&self, input: &str) -> Result<String>;
+
+    fn part_2(&self, input: &str) -> Result<String>;
+
+    fn solve(&self, part: Part, input: &str) -> Result<String> {
+        match part {
+            Part::One => self.part_1(input),
+            Part::Two => self.part_2(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!(" 2\n".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+}
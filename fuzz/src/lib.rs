@@ -0,0 +1,16 @@
+//! Fuzz targets feeding arbitrary bytes to the parser and both solvers of each day, which must
+//! reject them with an error rather than panic, overflow or hang. Run one with
+//! `cargo +nightly fuzz run day15 -- -timeout=10`, so that inputs taking longer count as hangs.
+
+use aoc_common::{Part, Solution};
+
+/// Parses and solves `data`, with invalid UTF-8 replaced since the runner only ever hands over
+/// text.
+pub fn check(solution: &dyn Solution, data: &[u8]) {
+    let input = String::from_utf8_lossy(data);
+    // Malformed inputs are expected to fail, only panics and hangs are bugs.
+    let _ = solution.parse(&input);
+    for part in Part::ALL {
+        let _ = solution.solve(part, &input);
+    }
+}
@@ -0,0 +1,235 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use generic_search::{astar, bfs, dfs};
+
+/// The searches as they were before nodes moved into an arena, each child holding an `Rc` to its
+/// parent, kept as the baseline the arena is measured against.
+mod rc {
+    use std::{
+        cmp::Ordering,
+        collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+        hash::Hash,
+        rc::Rc,
+    };
+
+    pub struct Node<T> {
+        state: T,
+        parent: Option<Rc<Node<T>>>,
+        cost: usize,
+        heuristic: usize,
+    }
+
+    impl<T: Clone> Node<T> {
+        fn new(state: T, parent: Option<Rc<Node<T>>>, cost: usize, heuristic: usize) -> Self {
+            Self {
+                state,
+                parent,
+                cost,
+                heuristic,
+            }
+        }
+
+        pub fn get_cost(&self) -> usize {
+            self.cost
+        }
+
+        pub fn node_to_path(&self) -> Vec<T> {
+            let mut current_node = self;
+            let mut path = vec![current_node.state.clone()];
+            while let Some(ref parent) = current_node.parent {
+                current_node = parent.as_ref();
+                path.push(current_node.state.clone());
+            }
+            path.reverse();
+            path
+        }
+    }
+
+    impl<T> PartialEq for Node<T> {
+        fn eq(&self, other: &Self) -> bool {
+            (self.cost + self.heuristic) == (other.cost + other.heuristic)
+        }
+    }
+
+    impl<T> Eq for Node<T> {}
+
+    impl<T> Ord for Node<T> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            (other.cost + other.heuristic).cmp(&(self.cost + self.heuristic))
+        }
+    }
+
+    impl<T> PartialOrd for Node<T> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    pub fn dfs<T: Clone + Eq + Hash>(
+        initial: T,
+        goal_test: impl Fn(&T) -> bool,
+        successors: impl Fn(&T) -> Vec<T>,
+    ) -> Option<Node<T>> {
+        let mut frontier = vec![Node::new(initial.clone(), None, 0, 0)];
+        let mut explored = HashSet::from([initial]);
+        while let Some(current_node) = frontier.pop() {
+            if goal_test(&current_node.state) {
+                return Some(current_node);
+            }
+            let current_node = Rc::new(current_node);
+            for child in successors(&current_node.state) {
+                if explored.insert(child.clone()) {
+                    frontier.push(Node::new(child, Some(Rc::clone(&current_node)), 0, 0));
+                }
+            }
+        }
+        None
+    }
+
+    pub fn bfs<T: Clone + Eq + Hash>(
+        initial: T,
+        goal_test: impl Fn(&T) -> bool,
+        successors: impl Fn(&T) -> Vec<T>,
+    ) -> Option<Node<T>> {
+        let mut frontier = VecDeque::from([Node::new(initial.clone(), None, 0, 0)]);
+        let mut explored = HashSet::from([initial]);
+        while let Some(current_node) = frontier.pop_front() {
+            if goal_test(&current_node.state) {
+                return Some(current_node);
+            }
+            let current_node = Rc::new(current_node);
+            for child in successors(&current_node.state) {
+                if explored.insert(child.clone()) {
+                    frontier.push_back(Node::new(child, Some(Rc::clone(&current_node)), 0, 0));
+                }
+            }
+        }
+        None
+    }
+
+    pub fn astar<T: Clone + Eq + Hash>(
+        initial: T,
+        goal_test: impl Fn(&T) -> bool,
+        successors: impl Fn(&T) -> Vec<T>,
+        heuristic: impl Fn(&T) -> usize,
+        cost: impl Fn(&Node<T>, &T) -> usize,
+    ) -> Option<Node<T>> {
+        let mut frontier =
+            BinaryHeap::from([Node::new(initial.clone(), None, 0, heuristic(&initial))]);
+        let mut explored = HashMap::from([(initial, 0)]);
+        while let Some(current_node) = frontier.pop() {
+            if current_node.cost > explored[&current_node.state] {
+                continue;
+            }
+            if goal_test(&current_node.state) {
+                return Some(current_node);
+            }
+            let current_node = Rc::new(current_node);
+            for child in successors(&current_node.state) {
+                let new_cost = cost(&current_node, &child);
+                match explored.entry(child.clone()) {
+                    Entry::Occupied(e) if new_cost >= *e.get() => continue,
+                    Entry::Occupied(mut e) => {
+                        e.insert(new_cost);
+                    }
+                    Entry::Vacant(e) => {
+                        e.insert(new_cost);
+                    }
+                }
+                let h = heuristic(&child);
+                frontier.push(Node::new(
+                    child,
+                    Some(Rc::clone(&current_node)),
+                    new_cost,
+                    h,
+                ));
+            }
+        }
+        None
+    }
+}
+
+type Cell = (usize, usize);
+
+/// An open `size`x`size` grid with a pillar on every odd row and column, like the mazes of
+/// days 16, 18 and 20 but without dead ends.
+fn successors(size: usize) -> impl Fn(&Cell) -> Vec<Cell> {
+    let is_open = |(x, y): Cell| x % 2 == 0 || y % 2 == 0;
+    move |&(x, y)| {
+        let mut successors = Vec::with_capacity(4);
+        if x > 0 && is_open((x - 1, y)) {
+            successors.push((x - 1, y));
+        }
+        if y > 0 && is_open((x, y - 1)) {
+            successors.push((x, y - 1));
+        }
+        if x + 1 < size && is_open((x + 1, y)) {
+            successors.push((x + 1, y));
+        }
+        if y + 1 < size && is_open((x, y + 1)) {
+            successors.push((x, y + 1));
+        }
+        successors
+    }
+}
+
+fn bench_searches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("grid");
+    for size in [71, 141, 281] {
+        let goal = (size - 1, size - 1);
+        group.bench_with_input(BenchmarkId::new("dfs", size), &size, |b, &size| {
+            b.iter(|| {
+                let node = dfs((0, 0), |s| *s == goal, successors(size)).unwrap();
+                black_box(node.node_to_path().len())
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("dfs_rc", size), &size, |b, &size| {
+            b.iter(|| {
+                let node = rc::dfs((0, 0), |s| *s == goal, successors(size)).unwrap();
+                black_box(node.node_to_path().len())
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("bfs", size), &size, |b, &size| {
+            b.iter(|| {
+                let node = bfs((0, 0), |s| *s == goal, successors(size)).unwrap();
+                black_box(node.node_to_path().len())
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("bfs_rc", size), &size, |b, &size| {
+            b.iter(|| {
+                let node = rc::bfs((0, 0), |s| *s == goal, successors(size)).unwrap();
+                black_box(node.node_to_path().len())
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("astar", size), &size, |b, &size| {
+            b.iter(|| {
+                let node = astar(
+                    (0, 0),
+                    |s| *s == goal,
+                    successors(size),
+                    // Deliberately weak, so that most of the grid is expanded.
+                    |&(x, y)| (goal.0 - x + goal.1 - y) / 4,
+                    |parent, _| parent.get_cost() + 1,
+                )
+                .unwrap();
+                black_box(node.get_cost())
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("astar_rc", size), &size, |b, &size| {
+            b.iter(|| {
+                let node = rc::astar(
+                    (0, 0),
+                    |s| *s == goal,
+                    successors(size),
+                    |&(x, y)| (goal.0 - x + goal.1 - y) / 4,
+                    |parent, _| parent.get_cost() + 1,
+                )
+                .unwrap();
+                black_box(node.get_cost())
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_searches);
+criterion_main!(benches);
@@ -0,0 +1,189 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::bfs_distances;
+
+/// Shortest distances between every pair of a fixed set of states.
+pub struct AllPairs<T> {
+    states: Vec<T>,
+    index: HashMap<T, usize>,
+    distances: Vec<Vec<Option<usize>>>,
+}
+
+impl<T> AllPairs<T>
+where
+    T: Clone + Eq + Hash,
+{
+    fn new(states: Vec<T>) -> Self {
+        let index = states
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.clone(), i))
+            .collect();
+        let n = states.len();
+        Self {
+            states,
+            index,
+            distances: vec![vec![None; n]; n],
+        }
+    }
+
+    /// Cost of the shortest path from `from` to `to`, or `None` if either state is unknown or
+    /// `to` cannot be reached.
+    pub fn distance(&self, from: &T, to: &T) -> Option<usize> {
+        let (&i, &j) = (self.index.get(from)?, self.index.get(to)?);
+        self.distances[i][j]
+    }
+
+    /// The states the distances were computed for.
+    pub fn states(&self) -> &[T] {
+        &self.states
+    }
+}
+
+/// Floyd–Warshall over the graph induced by `states`: successors outside of `states` are ignored.
+///
+/// Runs in `O(n³)` time and `O(n²)` memory, so it is meant for small sets of interesting states
+/// (junctions, keys, ...) rather than whole grids. When several edges join the same pair of
+/// states, the cheapest one is used.
+pub fn floyd_warshall<T, G>(states: Vec<T>, successors: G) -> AllPairs<T>
+where
+    T: Clone + Eq + Hash,
+    G: Fn(&T) -> Vec<(T, usize)>,
+{
+    let mut all_pairs = AllPairs::new(states);
+    let AllPairs {
+        states,
+        index,
+        distances,
+    } = &mut all_pairs;
+
+    for (i, (state, row)) in states.iter().zip(distances.iter_mut()).enumerate() {
+        row[i] = Some(0);
+        for (child, step_cost) in successors(state) {
+            if let Some(&j) = index.get(&child) {
+                row[j] = Some(row[j].map_or(step_cost, |c| c.min(step_cost)));
+            }
+        }
+    }
+
+    for k in 0..states.len() {
+        let row_k = distances[k].clone();
+        for row_i in distances.iter_mut() {
+            let Some(d_ik) = row_i[k] else {
+                continue;
+            };
+            for (d_ij, d_kj) in row_i.iter_mut().zip(&row_k) {
+                if let Some(d_kj) = d_kj {
+                    let through_k = d_ik + d_kj;
+                    if d_ij.is_none_or(|d_ij| through_k < d_ij) {
+                        *d_ij = Some(through_k);
+                    }
+                }
+            }
+        }
+    }
+    all_pairs
+}
+
+/// All-pairs distances of an unweighted graph, running one [`bfs_distances`] per state.
+///
+/// Like [`floyd_warshall`], only the graph induced by `states` is explored, but this runs in
+/// `O(n · e)` which is much faster on sparse graphs such as grids.
+pub fn all_pairs_bfs<T, G>(states: Vec<T>, successors: G) -> AllPairs<T>
+where
+    T: Clone + Eq + Hash,
+    G: Fn(&T) -> Vec<T>,
+{
+    let mut all_pairs = AllPairs::new(states);
+    let induced_successors = |state: &T| -> Vec<T> {
+        successors(state)
+            .into_iter()
+            .filter(|child| all_pairs.index.contains_key(child))
+            .collect()
+    };
+
+    let distances: Vec<Vec<Option<usize>>> = all_pairs
+        .states
+        .iter()
+        .map(|from| {
+            let reached = bfs_distances(from.clone(), induced_successors);
+            all_pairs
+                .states
+                .iter()
+                .map(|to| reached.get(to).copied())
+                .collect()
+        })
+        .collect();
+    all_pairs.distances = distances;
+    all_pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    /// A 3x3 grid without walls where moving right costs 1 and moving down costs 2.
+    fn weighted_successors(&(x, y): &(usize, usize)) -> Vec<((usize, usize), usize)> {
+        let mut successors = vec![];
+        if x < 2 {
+            successors.push(((x + 1, y), 1));
+        }
+        if y < 2 {
+            successors.push(((x, y + 1), 2));
+        }
+        successors
+    }
+
+    fn grid_states() -> Vec<(usize, usize)> {
+        (0..3).flat_map(|y| (0..3).map(move |x| (x, y))).collect()
+    }
+
+    #[rstest]
+    #[case((0, 0), (2, 2), Some(6))]
+    #[case((0, 0), (2, 0), Some(2))]
+    #[case((1, 1), (1, 1), Some(0))]
+    #[case((2, 2), (0, 0), None)]
+    fn test_floyd_warshall(
+        #[case] from: (usize, usize),
+        #[case] to: (usize, usize),
+        #[case] expected: Option<usize>,
+    ) {
+        let all_pairs = floyd_warshall(grid_states(), weighted_successors);
+        assert_eq!(all_pairs.distance(&from, &to), expected);
+    }
+
+    #[rstest]
+    fn test_floyd_warshall_ignores_states_outside_the_set() {
+        // Without (1, 0) and (1, 1) the only way from (0, 0) to (2, 0) is around the bottom.
+        let states: Vec<(usize, usize)> = grid_states()
+            .into_iter()
+            .filter(|&s| s != (1, 0) && s != (1, 1))
+            .collect();
+        let all_pairs = floyd_warshall(states, weighted_successors);
+        assert_eq!(all_pairs.distance(&(0, 0), &(2, 2)), Some(6));
+        assert_eq!(all_pairs.distance(&(0, 0), &(2, 0)), None);
+        assert_eq!(all_pairs.distance(&(0, 0), &(1, 0)), None);
+    }
+
+    #[rstest]
+    fn test_all_pairs_bfs_matches_floyd_warshall() {
+        let unit_successors = |s: &(usize, usize)| -> Vec<((usize, usize), usize)> {
+            weighted_successors(s)
+                .into_iter()
+                .map(|(c, _)| (c, 1))
+                .collect()
+        };
+        let floyd = floyd_warshall(grid_states(), unit_successors);
+        let bfs = all_pairs_bfs(grid_states(), |s| {
+            unit_successors(s).into_iter().map(|(c, _)| c).collect()
+        });
+        for from in grid_states() {
+            for to in grid_states() {
+                assert_eq!(floyd.distance(&from, &to), bfs.distance(&from, &to));
+            }
+        }
+        assert_eq!(bfs.distance(&(0, 0), &(2, 2)), Some(4));
+        assert_eq!(bfs.states().len(), 9);
+    }
+}
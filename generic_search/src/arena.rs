@@ -0,0 +1,39 @@
+/// Every node expanded by a search, stored by index.
+///
+/// A node only keeps the index of its parent, so expanding it costs one `Vec` push instead of
+/// an `Rc` allocation, and paths are recovered by following indices back to the root.
+pub(crate) struct Arena<T> {
+    states: Vec<T>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<T> Arena<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            states: Vec::new(),
+            parents: Vec::new(),
+        }
+    }
+
+    /// Index the next pushed state will get.
+    pub(crate) fn next_index(&self) -> usize {
+        self.states.len()
+    }
+
+    pub(crate) fn push(&mut self, state: T, parent: Option<usize>) -> usize {
+        self.states.push(state);
+        self.parents.push(parent);
+        self.states.len() - 1
+    }
+
+    /// Removes the last pushed state, which no other state may have as its parent.
+    pub(crate) fn pop(&mut self) {
+        self.states.pop();
+        self.parents.pop();
+    }
+
+    /// The state at `index` followed by all of its ancestors, up to the root.
+    pub(crate) fn ancestors(&self, index: Option<usize>) -> impl Iterator<Item = &T> {
+        std::iter::successors(index, |&i| self.parents[i]).map(|i| &self.states[i])
+    }
+}
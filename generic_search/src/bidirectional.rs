@@ -0,0 +1,456 @@
+use std::{
+    collections::{hash_map::Entry, BinaryHeap, HashMap},
+    hash::Hash,
+    time::Instant,
+};
+
+use crate::{Node, SearchStats};
+
+/// Breadth-first search from both ends at once. Finds a path with the fewest steps from `initial`
+/// to `goal`, or `None` if there is none.
+///
+/// `predecessors` must return the states with an edge _into_ the given state; for undirected
+/// graphs it is the same closure as `successors`. Each round expands a whole layer of the smaller
+/// frontier, so only about half of the search depth is explored from either side.
+pub fn bidirectional_bfs<T, G, P>(
+    initial: T,
+    goal: T,
+    successors: G,
+    predecessors: P,
+) -> Option<Vec<T>>
+where
+    T: Clone + Eq + Hash,
+    G: Fn(&T) -> Vec<T>,
+    P: Fn(&T) -> Vec<T>,
+{
+    bidirectional_bfs_with_stats(initial, goal, successors, predecessors).0
+}
+
+/// [`bidirectional_bfs`], also returning how much work the search did. The frontier counts the
+/// states waiting in both layers.
+pub fn bidirectional_bfs_with_stats<T, G, P>(
+    initial: T,
+    goal: T,
+    successors: G,
+    predecessors: P,
+) -> (Option<Vec<T>>, SearchStats)
+where
+    T: Clone + Eq + Hash,
+    G: Fn(&T) -> Vec<T>,
+    P: Fn(&T) -> Vec<T>,
+{
+    let start = Instant::now();
+    let mut stats = SearchStats::default();
+    if initial == goal {
+        return (Some(vec![initial]), stats.finish(start));
+    }
+
+    // Every visited state with its distance from its own end and the state it was reached from.
+    let mut forward = HashMap::from([(initial.clone(), (0, None))]);
+    let mut backward = HashMap::from([(goal.clone(), (0, None))]);
+    let mut forward_layer = vec![initial];
+    let mut backward_layer = vec![goal];
+    stats.record_frontier(2);
+
+    while !forward_layer.is_empty() && !backward_layer.is_empty() {
+        let meeting = if forward_layer.len() <= backward_layer.len() {
+            expand_layer(
+                &mut forward_layer,
+                &mut forward,
+                &backward,
+                &successors,
+                &mut stats,
+            )
+        } else {
+            expand_layer(
+                &mut backward_layer,
+                &mut backward,
+                &forward,
+                &predecessors,
+                &mut stats,
+            )
+        };
+        stats.record_frontier(forward_layer.len() + backward_layer.len());
+        if let Some(middle) = meeting {
+            let path = join_paths(middle, &forward, &backward);
+            return (Some(path), stats.finish(start));
+        }
+    }
+    (None, stats.finish(start))
+}
+
+/// Replaces `layer` with the next one, returning the best state where both searches meet.
+///
+/// The whole layer is expanded before settling on a meeting point, otherwise a longer path could
+/// be returned.
+fn expand_layer<T, N>(
+    layer: &mut Vec<T>,
+    visited: &mut HashMap<T, (usize, Option<T>)>,
+    other: &HashMap<T, (usize, Option<T>)>,
+    neighbors: N,
+    stats: &mut SearchStats,
+) -> Option<T>
+where
+    T: Clone + Eq + Hash,
+    N: Fn(&T) -> Vec<T>,
+{
+    let mut meeting: Option<(usize, T)> = None;
+    let mut next_layer = vec![];
+    for state in layer.drain(..) {
+        stats.expanded += 1;
+        let distance = visited[&state].0 + 1;
+        for child in neighbors(&state) {
+            stats.generated += 1;
+            if let Entry::Vacant(e) = visited.entry(child.clone()) {
+                e.insert((distance, Some(state.clone())));
+                if let Some(&(other_distance, _)) = other.get(&child) {
+                    let total = distance + other_distance;
+                    if meeting.as_ref().is_none_or(|(best, _)| total < *best) {
+                        meeting = Some((total, child.clone()));
+                    }
+                }
+                next_layer.push(child);
+            }
+        }
+    }
+    *layer = next_layer;
+    meeting.map(|(_, middle)| middle)
+}
+
+/// A* from both ends at once. Returns a minimum-cost path from `initial` to `goal` together with
+/// its cost.
+///
+/// `heuristic` estimates the cost to `goal` and `reverse_heuristic` the cost back to `initial`;
+/// both must be consistent. `step_cost` gives the cost of the edge from its first argument to its
+/// second and is called with edges in their original direction from both searches. The side with
+/// the smaller frontier is expanded first, and the search stops once neither frontier can lead
+/// to a cheaper path than the best meeting found so far.
+pub fn bidirectional_astar<T, G, P, H, R, C>(
+    initial: T,
+    goal: T,
+    successors: G,
+    predecessors: P,
+    heuristic: H,
+    reverse_heuristic: R,
+    step_cost: C,
+) -> Option<(Vec<T>, usize)>
+where
+    T: Clone + Eq + Hash,
+    G: Fn(&T) -> Vec<T>,
+    P: Fn(&T) -> Vec<T>,
+    H: Fn(&T) -> usize,
+    R: Fn(&T) -> usize,
+    C: Fn(&T, &T) -> usize,
+{
+    bidirectional_astar_with_stats(
+        initial,
+        goal,
+        successors,
+        predecessors,
+        heuristic,
+        reverse_heuristic,
+        step_cost,
+    )
+    .0
+}
+
+/// [`bidirectional_astar`], also returning how much work the search did. The frontier counts the
+/// nodes waiting in both heaps.
+pub fn bidirectional_astar_with_stats<T, G, P, H, R, C>(
+    initial: T,
+    goal: T,
+    successors: G,
+    predecessors: P,
+    heuristic: H,
+    reverse_heuristic: R,
+    step_cost: C,
+) -> (Option<(Vec<T>, usize)>, SearchStats)
+where
+    T: Clone + Eq + Hash,
+    G: Fn(&T) -> Vec<T>,
+    P: Fn(&T) -> Vec<T>,
+    H: Fn(&T) -> usize,
+    R: Fn(&T) -> usize,
+    C: Fn(&T, &T) -> usize,
+{
+    let start = Instant::now();
+    let mut stats = SearchStats::default();
+    let mut forward_frontier = BinaryHeap::new();
+    let mut backward_frontier = BinaryHeap::new();
+    forward_frontier.push(Node::new(initial.clone(), None, 0, heuristic(&initial)));
+    backward_frontier.push(Node::new(goal.clone(), None, 0, reverse_heuristic(&goal)));
+
+    let mut forward = HashMap::from([(initial.clone(), (0, None))]);
+    let mut backward = HashMap::from([(goal.clone(), (0, None))]);
+    let mut best: Option<(usize, T)> = (initial == goal).then(|| (0, initial.clone()));
+    stats.record_frontier(2);
+
+    while let (Some(forward_top), Some(backward_top)) =
+        (forward_frontier.peek(), backward_frontier.peek())
+    {
+        let lower_bound = usize::max(
+            forward_top.cost + forward_top.heuristic,
+            backward_top.cost + backward_top.heuristic,
+        );
+        if best.as_ref().is_some_and(|(cost, _)| *cost <= lower_bound) {
+            break;
+        }
+
+        let expand_forward = forward_frontier.len() <= backward_frontier.len();
+        let current_node = if expand_forward {
+            forward_frontier.pop()
+        } else {
+            backward_frontier.pop()
+        }
+        .unwrap();
+        let (visited, other) = if expand_forward {
+            (&mut forward, &backward)
+        } else {
+            (&mut backward, &forward)
+        };
+        // A cheaper copy of this state has already been expanded.
+        if current_node.cost > visited[&current_node.state].0 {
+            stats.duplicates += 1;
+            continue;
+        }
+        stats.expanded += 1;
+
+        let children = if expand_forward {
+            successors(&current_node.state)
+        } else {
+            predecessors(&current_node.state)
+        };
+        for child in children {
+            stats.generated += 1;
+            let step = if expand_forward {
+                step_cost(&current_node.state, &child)
+            } else {
+                step_cost(&child, &current_node.state)
+            };
+            let new_cost = current_node.cost + step;
+            match visited.entry(child.clone()) {
+                Entry::Occupied(e) if e.get().0 <= new_cost => continue,
+                Entry::Occupied(mut e) => {
+                    e.insert((new_cost, Some(current_node.state.clone())));
+                }
+                Entry::Vacant(e) => {
+                    e.insert((new_cost, Some(current_node.state.clone())));
+                }
+            }
+            if let Some(&(other_cost, _)) = other.get(&child) {
+                let total = new_cost + other_cost;
+                if best.as_ref().is_none_or(|(cost, _)| total < *cost) {
+                    best = Some((total, child.clone()));
+                }
+            }
+            let h = if expand_forward {
+                heuristic(&child)
+            } else {
+                reverse_heuristic(&child)
+            };
+            let frontier = if expand_forward {
+                &mut forward_frontier
+            } else {
+                &mut backward_frontier
+            };
+            frontier.push(Node::new(child, None, new_cost, h));
+        }
+        stats.record_frontier(forward_frontier.len() + backward_frontier.len());
+    }
+
+    let path = best.map(|(cost, middle)| (join_paths(middle, &forward, &backward), cost));
+    (path, stats.finish(start))
+}
+
+/// Follows the parents of `middle` back to the initial state and forward to the goal.
+fn join_paths<T>(
+    middle: T,
+    forward: &HashMap<T, (usize, Option<T>)>,
+    backward: &HashMap<T, (usize, Option<T>)>,
+) -> Vec<T>
+where
+    T: Clone + Eq + Hash,
+{
+    let mut path = vec![middle.clone()];
+    let mut current = &middle;
+    while let Some((_, Some(parent))) = forward.get(current) {
+        path.push(parent.clone());
+        current = parent;
+    }
+    path.reverse();
+
+    let mut current = &middle;
+    while let Some((_, Some(next))) = backward.get(current) {
+        path.push(next.clone());
+        current = next;
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bfs, bfs_with_stats, dijkstra};
+    use rstest::*;
+
+    type Cell = (usize, usize);
+
+    /// Open cells of a maze, `#` being walls.
+    fn open_cells(maze: &str) -> Vec<Vec<bool>> {
+        maze.lines()
+            .map(|line| line.chars().map(|c| c != '#').collect())
+            .collect()
+    }
+
+    fn neighbors(cells: &[Vec<bool>]) -> impl Fn(&Cell) -> Vec<Cell> + '_ {
+        move |&(x, y)| {
+            let mut neighbors = vec![];
+            if x > 0 && cells[y][x - 1] {
+                neighbors.push((x - 1, y));
+            }
+            if y > 0 && cells[y - 1][x] {
+                neighbors.push((x, y - 1));
+            }
+            if x + 1 < cells[y].len() && cells[y][x + 1] {
+                neighbors.push((x + 1, y));
+            }
+            if y + 1 < cells.len() && cells[y + 1][x] {
+                neighbors.push((x, y + 1));
+            }
+            neighbors
+        }
+    }
+
+    fn is_valid_path(path: &[Cell], cells: &[Vec<bool>]) -> bool {
+        path.windows(2)
+            .all(|w| neighbors(cells)(&w[0]).contains(&w[1]))
+    }
+
+    fn manhattan_to((gx, gy): Cell) -> impl Fn(&Cell) -> usize {
+        move |&(x, y)| gx.abs_diff(x) + gy.abs_diff(y)
+    }
+
+    #[fixture]
+    fn maze() -> &'static str {
+        "\
+.....#....
+.###.#.##.
+.#...#..#.
+.#.####.#.
+.#......#.
+.######.#.
+........#."
+    }
+
+    #[rstest]
+    #[case((0, 0), (9, 6))]
+    #[case((0, 0), (4, 2))]
+    #[case((9, 6), (6, 0))]
+    #[case((2, 2), (2, 2))]
+    fn test_bidirectional_bfs_matches_bfs(maze: &str, #[case] from: Cell, #[case] to: Cell) {
+        let cells = open_cells(maze);
+        let expected = bfs(from, |s| *s == to, neighbors(&cells))
+            .unwrap()
+            .node_to_path();
+        let path = bidirectional_bfs(from, to, neighbors(&cells), neighbors(&cells)).unwrap();
+        assert_eq!(path.len(), expected.len());
+        assert_eq!(path.first(), Some(&from));
+        assert_eq!(path.last(), Some(&to));
+        assert!(is_valid_path(&path, &cells));
+    }
+
+    #[rstest]
+    fn test_bidirectional_bfs_stats(maze: &str) {
+        let cells = open_cells(maze);
+        let (_, bfs_stats) = bfs_with_stats((0, 0), |s| *s == (9, 6), neighbors(&cells));
+        let (path, stats) =
+            bidirectional_bfs_with_stats((0, 0), (9, 6), neighbors(&cells), neighbors(&cells));
+        assert!(path.is_some());
+        assert!(stats.expanded < bfs_stats.expanded);
+        assert!(stats.generated >= stats.expanded);
+        assert_eq!(stats.duplicates, 0);
+    }
+
+    #[rstest]
+    fn test_bidirectional_bfs_no_path() {
+        let cells = open_cells("..#..\n..#..");
+        assert!(bidirectional_bfs((0, 0), (4, 1), neighbors(&cells), neighbors(&cells)).is_none());
+    }
+
+    #[rstest]
+    fn test_bidirectional_bfs_follows_edge_direction() {
+        // A one-way cycle 0 -> 1 -> 2 -> 3 -> 0: going from 1 to 0 takes three steps.
+        let successors = |s: &u8| vec![(s + 1) % 4];
+        let predecessors = |s: &u8| vec![(s + 3) % 4];
+        let path = bidirectional_bfs(1, 0, successors, predecessors).unwrap();
+        assert_eq!(path, vec![1, 2, 3, 0]);
+    }
+
+    #[rstest]
+    #[case((0, 0), (9, 6))]
+    #[case((9, 0), (0, 6))]
+    #[case((3, 4), (3, 4))]
+    fn test_bidirectional_astar_matches_dijkstra(maze: &str, #[case] from: Cell, #[case] to: Cell) {
+        let cells = open_cells(maze);
+        // Moving down or right is cheap, moving up or left is expensive.
+        let step_cost = |a: &Cell, b: &Cell| if b.0 > a.0 || b.1 > a.1 { 1 } else { 3 };
+        let weighted = |s: &Cell| {
+            neighbors(&cells)(s)
+                .into_iter()
+                .map(|c| (c, step_cost(s, &c)))
+                .collect()
+        };
+        let expected = dijkstra(from, weighted)[&to];
+
+        let (path, cost) = bidirectional_astar(
+            from,
+            to,
+            neighbors(&cells),
+            neighbors(&cells),
+            manhattan_to(to),
+            manhattan_to(from),
+            step_cost,
+        )
+        .unwrap();
+        assert_eq!(cost, expected);
+        assert_eq!(path.first(), Some(&from));
+        assert_eq!(path.last(), Some(&to));
+        assert!(is_valid_path(&path, &cells));
+        let path_cost: usize = path.windows(2).map(|w| step_cost(&w[0], &w[1])).sum();
+        assert_eq!(path_cost, cost);
+    }
+
+    #[rstest]
+    fn test_bidirectional_astar_stats(maze: &str) {
+        let cells = open_cells(maze);
+        let (path, stats) = bidirectional_astar_with_stats(
+            (0, 0),
+            (9, 6),
+            neighbors(&cells),
+            neighbors(&cells),
+            manhattan_to((9, 6)),
+            manhattan_to((0, 0)),
+            |_, _| 1,
+        );
+        assert!(path.is_some());
+        assert!(stats.expanded > 0);
+        assert!(stats.generated >= stats.expanded);
+        // Both ends start with a node in their frontier.
+        assert!(stats.max_frontier >= 2);
+    }
+
+    #[rstest]
+    fn test_bidirectional_astar_no_path() {
+        let cells = open_cells("..#..\n..#..");
+        let result = bidirectional_astar(
+            (0, 0),
+            (4, 1),
+            neighbors(&cells),
+            neighbors(&cells),
+            manhattan_to((4, 1)),
+            manhattan_to((0, 0)),
+            |_, _| 1,
+        );
+        assert!(result.is_none());
+    }
+}
@@ -0,0 +1,314 @@
+use std::{rc::Rc, vec::IntoIter};
+
+use crate::{arena::Arena, Node};
+
+/// Result of one bounded depth-first pass.
+enum Outcome<T> {
+    Found(Vec<Node<T>>),
+    /// Some states were left unexpanded because of the bound.
+    Cutoff,
+    /// Every state within reach was explored.
+    Exhausted,
+}
+
+/// Depth-first search that never goes more than `limit` steps away from `initial`.
+///
+/// Unlike [`dfs`](crate::dfs), no `explored` set is kept: only states on the current path are
+/// remembered, to avoid cycles, so memory grows with `limit` rather than with the size of the
+/// state space. States may be visited several times through different paths.
+pub fn depth_limited_dfs<T, F, G>(
+    initial: T,
+    goal_test: F,
+    successors: G,
+    limit: usize,
+) -> Option<Node<T>>
+where
+    T: Clone + Eq,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+{
+    match bounded_dfs(
+        Node::new(initial, None, 0, 0),
+        &goal_test,
+        &successors,
+        |parent, _| (parent.cost + 1, 0),
+        |node| node.cost <= limit,
+    ) {
+        Outcome::Found(path) => Some(path_to_node(path)),
+        Outcome::Cutoff | Outcome::Exhausted => None,
+    }
+}
+
+/// Iterative-deepening DFS: [`depth_limited_dfs`] with a limit of 0, 1, 2, ... until the goal is
+/// found or the whole reachable state space fits within the limit.
+///
+/// Finds a path with the fewest steps, like [`bfs`](crate::bfs), with the memory footprint of a
+/// depth-first search.
+pub fn iddfs<T, F, G>(initial: T, goal_test: F, successors: G) -> Option<Node<T>>
+where
+    T: Clone + Eq,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+{
+    for limit in 0.. {
+        match bounded_dfs(
+            Node::new(initial.clone(), None, 0, 0),
+            &goal_test,
+            &successors,
+            |parent, _| (parent.cost + 1, 0),
+            |node| node.cost <= limit,
+        ) {
+            Outcome::Found(path) => return Some(path_to_node(path)),
+            Outcome::Cutoff => continue,
+            Outcome::Exhausted => return None,
+        }
+    }
+    None
+}
+
+/// Iterative-deepening A*: depth-first passes bounded by `cost + heuristic`, each bound being the
+/// smallest value that exceeded the previous one.
+///
+/// Takes the same closures as [`astar`](crate::astar) and finds a minimum-cost path as long as
+/// the heuristic never overestimates, while only keeping the current path in memory. The parent
+/// node given to `cost` can walk back that path.
+pub fn ida_star<T, F, G, H, C>(
+    initial: T,
+    goal_test: F,
+    successors: G,
+    heuristic: H,
+    cost: C,
+) -> Option<Node<T>>
+where
+    T: Clone + Eq,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+    H: Fn(&T) -> usize,
+    C: Fn(&Node<T>, &T) -> usize,
+{
+    let mut bound = heuristic(&initial);
+    loop {
+        // Smallest `cost + heuristic` that was pruned during this pass.
+        let mut next_bound = None;
+        let outcome = bounded_dfs(
+            Node::new(initial.clone(), None, 0, heuristic(&initial)),
+            &goal_test,
+            &successors,
+            |parent, child| (cost(parent, child), heuristic(child)),
+            |node| {
+                let f = node.cost + node.heuristic;
+                if f > bound {
+                    next_bound = Some(next_bound.map_or(f, |b: usize| b.min(f)));
+                }
+                f <= bound
+            },
+        );
+        match outcome {
+            Outcome::Found(path) => return Some(path_to_node(path)),
+            Outcome::Cutoff => bound = next_bound?,
+            Outcome::Exhausted => return None,
+        }
+    }
+}
+
+/// Iterative depth-first search from `root` that only enters nodes accepted by `within_bound`.
+///
+/// `evaluate` gives the cost and heuristic of a child from its parent node, whose ancestors are
+/// the states above it on the current path.
+fn bounded_dfs<T, F, G, E, B>(
+    root: Node<T>,
+    goal_test: &F,
+    successors: &G,
+    evaluate: E,
+    mut within_bound: B,
+) -> Outcome<T>
+where
+    T: Clone + Eq,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+    E: Fn(&Node<T>, &T) -> (usize, usize),
+    B: FnMut(&Node<T>) -> bool,
+{
+    if goal_test(&root.state) {
+        return Outcome::Found(vec![root]);
+    }
+    let mut cutoff = false;
+    let mut children: Vec<IntoIter<T>> = vec![successors(&root.state).into_iter()];
+    let mut path = vec![root];
+    // The states of every node on the path but the last, the node at position `i` having its
+    // parent at index `i - 1`.
+    let mut ancestors = Rc::new(Arena::new());
+
+    while let Some(pending) = children.last_mut() {
+        let Some(child) = pending.next() else {
+            children.pop();
+            path.pop();
+            if !path.is_empty() {
+                Rc::get_mut(&mut ancestors)
+                    .expect("Only the parent being evaluated borrows the arena")
+                    .pop();
+            }
+            continue;
+        };
+        if path.iter().any(|node| node.state == child) {
+            continue;
+        }
+        let parent = path.last_mut().unwrap();
+        parent.arena = Some(Rc::clone(&ancestors));
+        let (cost, heuristic) = evaluate(parent, &child);
+        parent.arena = None;
+        let node = Node::new(child, Some(path.len() - 1), cost, heuristic);
+        if !within_bound(&node) {
+            cutoff = true;
+            continue;
+        }
+        if goal_test(&node.state) {
+            path.push(node);
+            return Outcome::Found(path);
+        }
+        let parent = path.last().unwrap();
+        Rc::get_mut(&mut ancestors)
+            .expect("Only the parent being evaluated borrows the arena")
+            .push(parent.state.clone(), parent.parent);
+        children.push(successors(&node.state).into_iter());
+        path.push(node);
+    }
+
+    if cutoff {
+        Outcome::Cutoff
+    } else {
+        Outcome::Exhausted
+    }
+}
+
+/// Turns the nodes of a path into the goal node, with the rest of the path stored in its arena.
+fn path_to_node<T>(mut path: Vec<Node<T>>) -> Node<T> {
+    let goal = path.pop().unwrap();
+    let mut arena = Arena::new();
+    let mut parent = None;
+    for node in path {
+        parent = Some(arena.push(node.state, parent));
+    }
+    Node::new(goal.state, parent, goal.cost, goal.heuristic).with_arena(Rc::new(arena))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{astar, bfs};
+    use rstest::*;
+
+    /// An infinite state space: from `n` one can go to `n + 1` or `2 * n`.
+    fn double_or_increment(n: &u64) -> Vec<u64> {
+        vec![n + 1, 2 * n]
+    }
+
+    /// A 4x4 grid with a wall, moving in the four directions.
+    fn grid_successors(&(x, y): &(usize, usize)) -> Vec<(usize, usize)> {
+        let walls = [(1, 0), (1, 1), (1, 2), (2, 2)];
+        [(0, 1), (1, 0), (0, -1), (-1, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+                (x < 4 && y < 4 && !walls.contains(&(x, y))).then_some((x, y))
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(10, 4)]
+    #[case(1, 0)]
+    #[case(17, 5)]
+    fn test_iddfs_in_infinite_space(#[case] target: u64, #[case] expected_steps: usize) {
+        let node = iddfs(1, |n| *n == target, double_or_increment).unwrap();
+        assert_eq!(node.depth(), expected_steps);
+        assert_eq!(node.node_to_path().first(), Some(&1));
+        assert_eq!(*node.get_state(), target);
+    }
+
+    #[rstest]
+    #[case(3, false)]
+    #[case(4, true)]
+    #[case(9, true)]
+    fn test_depth_limited_dfs(#[case] limit: usize, #[case] found: bool) {
+        let node = depth_limited_dfs(1, |n| *n == 10, double_or_increment, limit);
+        assert_eq!(node.is_some(), found);
+        assert!(node.is_none_or(|n| n.depth() <= limit));
+    }
+
+    #[rstest]
+    fn test_iddfs_matches_bfs() {
+        let goal = (2, 0);
+        let expected = bfs((0, 0), |s| *s == goal, grid_successors).unwrap();
+        let node = iddfs((0, 0), |s| *s == goal, grid_successors).unwrap();
+        assert_eq!(node.depth(), expected.depth());
+        assert!(node
+            .node_to_path()
+            .windows(2)
+            .all(|w| grid_successors(&w[0]).contains(&w[1])));
+    }
+
+    #[rstest]
+    fn test_iddfs_no_path() {
+        assert!(iddfs((0, 0), |s| *s == (5, 5), grid_successors).is_none());
+    }
+
+    #[rstest]
+    fn test_ida_star_matches_astar() {
+        let goal = (3, 3);
+        let heuristic = |&(x, y): &(usize, usize)| goal.0 - x + goal.1 - y;
+        // Moving right is expensive.
+        let cost = |parent: &Node<(usize, usize)>, child: &(usize, usize)| {
+            parent.get_cost() + if child.0 > parent.get_state().0 { 3 } else { 1 }
+        };
+        let expected = astar((0, 0), |s| *s == goal, grid_successors, heuristic, cost).unwrap();
+        let node = ida_star((0, 0), |s| *s == goal, grid_successors, heuristic, cost).unwrap();
+        assert_eq!(node.get_cost(), expected.get_cost());
+        assert_eq!(node.node_to_path().last(), Some(&goal));
+    }
+
+    #[rstest]
+    fn test_ida_star_cost_sees_the_parent_path() {
+        // Each step costs the length of the path so far, which is only right if `cost` can read it.
+        let cost = |parent: &Node<(usize, usize)>, child: &(usize, usize)| {
+            let path = parent.node_to_path();
+            assert_eq!(path.first(), Some(&(0, 0)));
+            assert_eq!(path.last(), Some(parent.get_state()));
+            assert_eq!(path.len(), parent.depth() + 1);
+            assert!(path
+                .windows(2)
+                .all(|w| grid_successors(&w[0]).contains(&w[1])));
+            assert!(grid_successors(parent.get_state()).contains(child));
+            parent.get_cost() + path.len()
+        };
+        let node = ida_star((0, 0), |s| *s == (2, 0), grid_successors, |_| 0, cost).unwrap();
+        let expected = astar((0, 0), |s| *s == (2, 0), grid_successors, |_| 0, cost).unwrap();
+        assert_eq!(node.get_cost(), expected.get_cost());
+        assert_eq!(node.depth(), expected.depth());
+    }
+
+    #[rstest]
+    fn test_ida_star_in_infinite_space() {
+        let node = ida_star(
+            1,
+            |n| *n == 17,
+            double_or_increment,
+            |_| 0,
+            |parent, _| parent.get_cost() + 1,
+        )
+        .unwrap();
+        assert_eq!(node.get_cost(), 5);
+    }
+
+    #[rstest]
+    fn test_ida_star_no_path() {
+        let node = ida_star(
+            (0, 0),
+            |s| *s == (5, 5),
+            grid_successors,
+            |_| 0,
+            |parent, _| parent.get_cost() + 1,
+        );
+        assert!(node.is_none());
+    }
+}
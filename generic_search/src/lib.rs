@@ -0,0 +1,767 @@
+//! Generic search algorithms over implicit state spaces.
+//!
+//! States are any `Clone + Eq + Hash` type. The graph is never materialized: callers provide a
+//! `goal_test` closure and a `successors` closure that expands a state into its neighbors, plus a
+//! `heuristic` and a `cost` closure for the informed searches.
+//!
+//! Every search returns the goal [`Node`], from which the path back to the initial state can be
+//! recovered with [`Node::node_to_path`]. The `*_with_stats` variants also return a
+//! [`SearchStats`] with the number of expanded nodes, the peak frontier size and the time taken;
+//! every search has one except the distance tables and the iterative-deepening searches.
+//!
+//! For weighted graphs whose step costs are known up front, [`dijkstra`] and [`dijkstra_to_goal`]
+//! take successors paired with their cost. [`bfs_distances`], [`floyd_warshall`] and
+//! [`all_pairs_bfs`] return distance tables instead of a single path, and [`astar_dag`] returns
+//! a [`PathDag`] holding every minimum-cost path at once. When the goal is a single known state
+//! and edges can be followed backwards, [`bidirectional_bfs`] and [`bidirectional_astar`] search
+//! from both ends and meet in the middle. For state spaces too large to remember,
+//! [`depth_limited_dfs`], [`iddfs`] and [`ida_star`] only keep the current path in memory.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    rc::Rc,
+    time::Instant,
+};
+
+mod all_pairs;
+mod arena;
+mod bidirectional;
+mod iterative_deepening;
+mod path_dag;
+mod shortest_paths;
+mod stats;
+
+use arena::Arena;
+
+pub use all_pairs::{all_pairs_bfs, floyd_warshall, AllPairs};
+pub use bidirectional::{
+    bidirectional_astar, bidirectional_astar_with_stats, bidirectional_bfs,
+    bidirectional_bfs_with_stats,
+};
+pub use iterative_deepening::{depth_limited_dfs, ida_star, iddfs};
+pub use path_dag::{astar_dag, astar_dag_with_stats, PathDag};
+pub use shortest_paths::{
+    bfs_distances, dijkstra, dijkstra_to_goal, dijkstra_to_goal_with_stats, dijkstra_with_stats,
+};
+pub use stats::SearchStats;
+
+/// A state reached by a search, together with the node it was reached from.
+///
+/// Nodes are ordered by `cost + heuristic`, reversed so that a [`BinaryHeap`] pops the most
+/// promising node first. Parents are indices into the arena of expanded nodes, which the search
+/// hands over to the node it returns.
+pub struct Node<T> {
+    state: T,
+    parent: Option<usize>,
+    cost: usize,
+    heuristic: usize,
+    arena: Option<Rc<Arena<T>>>,
+}
+
+impl<T> Node<T> {
+    pub(crate) fn new(state: T, parent: Option<usize>, cost: usize, heuristic: usize) -> Self {
+        Self {
+            state,
+            parent,
+            cost,
+            heuristic,
+            arena: None,
+        }
+    }
+
+    /// Attaches the arena the parent indices point into.
+    pub(crate) fn with_arena(mut self, arena: Rc<Arena<T>>) -> Self {
+        self.arena = Some(arena);
+        self
+    }
+
+    /// Accumulated cost from the initial state (always 0 for `dfs` and `bfs`).
+    pub fn get_cost(&self) -> usize {
+        self.cost
+    }
+
+    pub fn get_state(&self) -> &T {
+        &self.state
+    }
+
+    /// This node's state followed by the states it was reached through, back to the initial
+    /// state. Nothing is cloned.
+    pub fn ancestors(&self) -> impl Iterator<Item = &T> {
+        let parents = self
+            .arena
+            .as_deref()
+            .into_iter()
+            .flat_map(|arena| arena.ancestors(self.parent));
+        std::iter::once(&self.state).chain(parents)
+    }
+
+    /// Number of steps from the initial state to this node.
+    pub fn depth(&self) -> usize {
+        self.ancestors().count() - 1
+    }
+}
+
+impl<T> PartialEq for Node<T> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.cost + self.heuristic) == (other.cost + other.heuristic)
+    }
+}
+
+impl<T> Eq for Node<T> {}
+
+impl<T> Ord for Node<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.cost + other.heuristic).cmp(&(self.cost + self.heuristic))
+    }
+}
+
+impl<T> PartialOrd for Node<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Node<T>
+where
+    T: Clone,
+{
+    /// States from the initial state up to (and including) this node.
+    pub fn node_to_path(&self) -> Vec<T> {
+        let mut path: Vec<T> = self.ancestors().cloned().collect();
+        path.reverse();
+        path
+    }
+}
+
+/// Depth-first search. Finds _a_ path to the goal, not necessarily the shortest one.
+pub fn dfs<T, F, G>(initial: T, goal_test: F, successors: G) -> Option<Node<T>>
+where
+    T: Clone + Eq + std::hash::Hash,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+{
+    dfs_with_stats(initial, goal_test, successors).0
+}
+
+/// [`dfs`], also returning how much work the search did.
+pub fn dfs_with_stats<T, F, G>(
+    initial: T,
+    goal_test: F,
+    successors: G,
+) -> (Option<Node<T>>, SearchStats)
+where
+    T: Clone + Eq + std::hash::Hash,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+{
+    let start = Instant::now();
+    let mut stats = SearchStats::default();
+    let mut frontier = Vec::new();
+    let mut explored = HashSet::new();
+    let mut arena = Arena::new();
+    frontier.push(Node::new(initial.clone(), None, 0, 0));
+    explored.insert(initial);
+    stats.record_frontier(frontier.len());
+
+    while let Some(current_node) = frontier.pop() {
+        if goal_test(&current_node.state) {
+            let node = current_node.with_arena(Rc::new(arena));
+            return (Some(node), stats.finish(start));
+        }
+        stats.expanded += 1;
+        let children = successors(&current_node.state);
+        let index = arena.push(current_node.state, current_node.parent);
+        children
+            .into_iter()
+            .inspect(|_| stats.generated += 1)
+            .filter(|c| explored.insert(c.clone()))
+            .for_each(|c| frontier.push(Node::new(c, Some(index), 0, 0)));
+        stats.record_frontier(frontier.len());
+    }
+    (None, stats.finish(start))
+}
+
+/// Breadth-first search. Finds a path with the fewest steps to the goal.
+pub fn bfs<T, F, G>(initial: T, goal_test: F, successors: G) -> Option<Node<T>>
+where
+    T: Clone + Eq + std::hash::Hash,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+{
+    bfs_with_stats(initial, goal_test, successors).0
+}
+
+/// [`bfs`], also returning how much work the search did.
+pub fn bfs_with_stats<T, F, G>(
+    initial: T,
+    goal_test: F,
+    successors: G,
+) -> (Option<Node<T>>, SearchStats)
+where
+    T: Clone + Eq + std::hash::Hash,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+{
+    let start = Instant::now();
+    let mut stats = SearchStats::default();
+    let mut frontier = VecDeque::new();
+    let mut explored = HashSet::new();
+    let mut arena = Arena::new();
+    frontier.push_back(Node::new(initial.clone(), None, 0, 0));
+    explored.insert(initial);
+    stats.record_frontier(frontier.len());
+
+    while let Some(current_node) = frontier.pop_front() {
+        if goal_test(&current_node.state) {
+            let node = current_node.with_arena(Rc::new(arena));
+            return (Some(node), stats.finish(start));
+        }
+        stats.expanded += 1;
+        let children = successors(&current_node.state);
+        let index = arena.push(current_node.state, current_node.parent);
+        children
+            .into_iter()
+            .inspect(|_| stats.generated += 1)
+            .filter(|c| explored.insert(c.clone()))
+            .for_each(|c| frontier.push_back(Node::new(c, Some(index), 0, 0)));
+        stats.record_frontier(frontier.len());
+    }
+    (None, stats.finish(start))
+}
+
+/// A* search. Finds a minimum-cost path to the goal provided the heuristic never overestimates.
+///
+/// `cost` receives the parent node and the child state and must return the _total_ cost of the
+/// child, usually `parent.get_cost()` plus the cost of the step.
+///
+/// Frontier entries superseded by a cheaper copy of the same state are skipped when popped. With
+/// a consistent heuristic every state is then expanded at most once; with an admissible but
+/// inconsistent one, a state found again more cheaply after its expansion is reopened, so the
+/// result stays optimal.
+pub fn astar<T, F, G, H, C>(
+    initial: T,
+    goal_test: F,
+    successors: G,
+    heuristic: H,
+    cost: C,
+) -> Option<Node<T>>
+where
+    T: Clone + Eq + std::hash::Hash,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+    H: Fn(&T) -> usize,
+    C: Fn(&Node<T>, &T) -> usize,
+{
+    astar_with_stats(initial, goal_test, successors, heuristic, cost).0
+}
+
+/// [`astar`], also returning how much work the search did.
+pub fn astar_with_stats<T, F, G, H, C>(
+    initial: T,
+    goal_test: F,
+    successors: G,
+    heuristic: H,
+    cost: C,
+) -> (Option<Node<T>>, SearchStats)
+where
+    T: Clone + Eq + std::hash::Hash,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+    H: Fn(&T) -> usize,
+    C: Fn(&Node<T>, &T) -> usize,
+{
+    let start = Instant::now();
+    let mut stats = SearchStats::default();
+    let mut frontier = BinaryHeap::new();
+    let mut explored = HashMap::new();
+    let mut arena = Rc::new(Arena::new());
+    frontier.push(Node::new(initial.clone(), None, 0, heuristic(&initial)));
+    explored.insert(initial, 0);
+    stats.record_frontier(frontier.len());
+
+    while let Some(current_node) = frontier.pop() {
+        // Superseded: a cheaper copy of this state was pushed after this one.
+        if current_node.cost > explored[&current_node.state] {
+            stats.duplicates += 1;
+            continue;
+        }
+        if goal_test(&current_node.state) {
+            let node = current_node.with_arena(arena);
+            return (Some(node), stats.finish(start));
+        }
+        stats.expanded += 1;
+        // Children point at the slot `current_node` takes once they have all been costed, and
+        // `cost` sees it with the arena so that it can walk back its path.
+        let index = arena.next_index();
+        let current_node = current_node.with_arena(Rc::clone(&arena));
+        successors(&current_node.state)
+            .into_iter()
+            .inspect(|_| stats.generated += 1)
+            .map(|child| (cost(&current_node, &child), child))
+            .for_each(|(new_cost, child)| match explored.entry(child.clone()) {
+                Entry::Vacant(e) => {
+                    e.insert(new_cost);
+                    let h = heuristic(&child);
+                    frontier.push(Node::new(child, Some(index), new_cost, h));
+                }
+                Entry::Occupied(mut e) if new_cost < *e.get() => {
+                    e.insert(new_cost);
+                    let h = heuristic(&child);
+                    frontier.push(Node::new(child, Some(index), new_cost, h));
+                }
+                _ => {}
+            });
+        push_expanded(&mut arena, current_node);
+        stats.record_frontier(frontier.len());
+    }
+    (None, stats.finish(start))
+}
+
+/// Like [`astar`], but keeps searching after the first goal and returns every goal node found.
+///
+/// States reached again with an equal cost are expanded once more, so all ties are explored, while
+/// superseded entries are skipped as in [`astar`]. The returned nodes are in the order they were
+/// popped, cheapest first. Each node only remembers one
+/// parent, so use [`astar_dag`] to get every optimal path.
+pub fn astar_paths<T, F, G, H, C>(
+    initial: T,
+    goal_test: F,
+    successors: G,
+    heuristic: H,
+    cost: C,
+) -> Option<Vec<Node<T>>>
+where
+    T: Clone + Eq + std::hash::Hash,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+    H: Fn(&T) -> usize,
+    C: Fn(&Node<T>, &T) -> usize,
+{
+    astar_paths_with_stats(initial, goal_test, successors, heuristic, cost).0
+}
+
+/// [`astar_paths`], also returning how much work the search did.
+pub fn astar_paths_with_stats<T, F, G, H, C>(
+    initial: T,
+    goal_test: F,
+    successors: G,
+    heuristic: H,
+    cost: C,
+) -> (Option<Vec<Node<T>>>, SearchStats)
+where
+    T: Clone + Eq + std::hash::Hash,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+    H: Fn(&T) -> usize,
+    C: Fn(&Node<T>, &T) -> usize,
+{
+    let start = Instant::now();
+    let mut stats = SearchStats::default();
+    let mut frontier = BinaryHeap::new();
+    let mut explored = HashMap::new();
+    let mut arena = Rc::new(Arena::new());
+    frontier.push(Node::new(initial.clone(), None, 0, heuristic(&initial)));
+    explored.insert(initial, 0);
+    stats.record_frontier(frontier.len());
+
+    let mut valid_paths = Vec::new();
+
+    while let Some(current_node) = frontier.pop() {
+        // Superseded: a cheaper copy of this state was pushed after this one.
+        if current_node.cost > explored[&current_node.state] {
+            stats.duplicates += 1;
+            continue;
+        }
+        if goal_test(&current_node.state) {
+            valid_paths.push(current_node);
+            continue;
+        }
+        stats.expanded += 1;
+        // Children point at the slot `current_node` takes once they have all been costed, and
+        // `cost` sees it with the arena so that it can walk back its path.
+        let index = arena.next_index();
+        let current_node = current_node.with_arena(Rc::clone(&arena));
+        successors(&current_node.state)
+            .into_iter()
+            .inspect(|_| stats.generated += 1)
+            .map(|child| (cost(&current_node, &child), child))
+            .for_each(|(new_cost, child)| match explored.entry(child.clone()) {
+                Entry::Vacant(e) => {
+                    e.insert(new_cost);
+                    let h = heuristic(&child);
+                    frontier.push(Node::new(child, Some(index), new_cost, h));
+                }
+                Entry::Occupied(mut e) if new_cost <= *e.get() => {
+                    e.insert(new_cost);
+                    let h = heuristic(&child);
+                    frontier.push(Node::new(child, Some(index), new_cost, h));
+                }
+                _ => {}
+            });
+        push_expanded(&mut arena, current_node);
+        stats.record_frontier(frontier.len());
+    }
+
+    let stats = stats.finish(start);
+    if valid_paths.is_empty() {
+        (None, stats)
+    } else {
+        let valid_paths = valid_paths
+            .into_iter()
+            .map(|node| node.with_arena(Rc::clone(&arena)))
+            .collect();
+        (Some(valid_paths), stats)
+    }
+}
+
+/// Stores a node once its children are costed, taking back the arena it was lent.
+fn push_expanded<T>(arena: &mut Rc<Arena<T>>, node: Node<T>) {
+    let Node {
+        state,
+        parent,
+        arena: lent,
+        ..
+    } = node;
+    drop(lent);
+    Rc::get_mut(arena)
+        .expect("No node outlives its expansion")
+        .push(state, parent);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    /// A small grid where `#` are walls, `S` is the start and `E` the goal.
+    struct Grid {
+        cells: Vec<Vec<char>>,
+        start: (usize, usize),
+        goal: (usize, usize),
+    }
+
+    impl Grid {
+        fn new(input: &str) -> Self {
+            let cells: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+            let find = |target| {
+                cells
+                    .iter()
+                    .enumerate()
+                    .find_map(|(y, row)| row.iter().position(|&c| c == target).map(|x| (x, y)))
+                    .unwrap()
+            };
+            let (start, goal) = (find('S'), find('E'));
+            Self { cells, start, goal }
+        }
+
+        fn successors(&self, &(x, y): &(usize, usize)) -> Vec<(usize, usize)> {
+            [(0, 1), (1, 0), (0, -1), (-1, 0)]
+                .into_iter()
+                .filter_map(|(dx, dy)| {
+                    let x = x.checked_add_signed(dx)?;
+                    let y = y.checked_add_signed(dy)?;
+                    let c = *self.cells.get(y)?.get(x)?;
+                    (c != '#').then_some((x, y))
+                })
+                .collect()
+        }
+
+        fn manhattan(&self, &(x, y): &(usize, usize)) -> usize {
+            x.abs_diff(self.goal.0) + y.abs_diff(self.goal.1)
+        }
+    }
+
+    #[fixture]
+    fn open_grid() -> Grid {
+        Grid::new(
+            "S....
+.....
+.....
+....E",
+        )
+    }
+
+    #[fixture]
+    fn walled_grid() -> Grid {
+        Grid::new(
+            "S.#..
+.##.#
+...#E
+.#...",
+        )
+    }
+
+    #[fixture]
+    fn blocked_grid() -> Grid {
+        Grid::new(
+            "S.#..
+..#.E
+..#..",
+        )
+    }
+
+    fn is_valid_path(grid: &Grid, path: &[(usize, usize)]) -> bool {
+        path.first() == Some(&grid.start)
+            && path.last() == Some(&grid.goal)
+            && path
+                .windows(2)
+                .all(|w| grid.successors(&w[0]).contains(&w[1]))
+    }
+
+    #[rstest]
+    fn test_dfs_finds_a_path(walled_grid: Grid) {
+        let node = dfs(
+            walled_grid.start,
+            |s| *s == walled_grid.goal,
+            |s| walled_grid.successors(s),
+        )
+        .unwrap();
+        assert!(is_valid_path(&walled_grid, &node.node_to_path()));
+    }
+
+    #[rstest]
+    #[case(open_grid(), 7)]
+    #[case(walled_grid(), 8)]
+    fn test_bfs_shortest_path(#[case] grid: Grid, #[case] expected_steps: usize) {
+        let node = bfs(grid.start, |s| *s == grid.goal, |s| grid.successors(s)).unwrap();
+        let path = node.node_to_path();
+        assert!(is_valid_path(&grid, &path));
+        assert_eq!(path.len() - 1, expected_steps);
+        assert_eq!(node.depth(), expected_steps);
+    }
+
+    #[rstest]
+    fn test_ancestors_walk_the_path_backwards(walled_grid: Grid) {
+        let grid = &walled_grid;
+        let node = astar(
+            grid.start,
+            |s| *s == grid.goal,
+            |s| grid.successors(s),
+            |s| grid.manhattan(s),
+            |parent, _| parent.get_cost() + 1,
+        )
+        .unwrap();
+        let mut ancestors: Vec<(usize, usize)> = node.ancestors().copied().collect();
+        assert_eq!(ancestors.first(), Some(&grid.goal));
+        ancestors.reverse();
+        assert_eq!(ancestors, node.node_to_path());
+    }
+
+    #[rstest]
+    fn test_cost_sees_the_parent_path(walled_grid: Grid) {
+        let grid = &walled_grid;
+        // Each step costs the length of the path so far, which is only right if `cost` can read it.
+        let cost = |parent: &Node<(usize, usize)>, child: &(usize, usize)| {
+            let path = parent.node_to_path();
+            assert_eq!(path.first(), Some(&grid.start));
+            assert_eq!(path.last(), Some(parent.get_state()));
+            assert_eq!(path.len(), parent.depth() + 1);
+            assert!(grid.successors(parent.get_state()).contains(child));
+            parent.get_cost() + path.len()
+        };
+        let node = astar(
+            grid.start,
+            |s| *s == grid.goal,
+            |s| grid.successors(s),
+            |_| 0,
+            cost,
+        )
+        .unwrap();
+        assert_eq!(node.get_cost(), (1..=8).sum());
+        let nodes = astar_paths(
+            grid.start,
+            |s| *s == grid.goal,
+            |s| grid.successors(s),
+            |_| 0,
+            cost,
+        )
+        .unwrap();
+        assert!(nodes.iter().all(|node| node.get_cost() == (1..=8).sum()));
+    }
+
+    #[rstest]
+    #[case(open_grid(), 7)]
+    #[case(walled_grid(), 8)]
+    fn test_astar_matches_bfs(#[case] grid: Grid, #[case] expected_cost: usize) {
+        let node = astar(
+            grid.start,
+            |s| *s == grid.goal,
+            |s| grid.successors(s),
+            |s| grid.manhattan(s),
+            |parent, _| parent.get_cost() + 1,
+        )
+        .unwrap();
+        assert_eq!(node.get_cost(), expected_cost);
+        assert_eq!(*node.get_state(), grid.goal);
+        assert!(is_valid_path(&grid, &node.node_to_path()));
+    }
+
+    #[rstest]
+    fn test_astar_weighted_costs() {
+        // Stepping onto a `~` costs 10, so the detour around the water is cheaper.
+        let grid = Grid::new(
+            "S~E
+...",
+        );
+        let cost = |parent: &Node<(usize, usize)>, &(x, y): &(usize, usize)| {
+            parent.get_cost() + if grid.cells[y][x] == '~' { 10 } else { 1 }
+        };
+        let node = astar(
+            grid.start,
+            |s| *s == grid.goal,
+            |s| grid.successors(s),
+            |s| grid.manhattan(s),
+            cost,
+        )
+        .unwrap();
+        assert_eq!(node.get_cost(), 4);
+        assert_eq!(
+            node.node_to_path(),
+            vec![(0, 0), (0, 1), (1, 1), (2, 1), (2, 0)]
+        );
+    }
+
+    #[rstest]
+    fn test_astar_paths_finds_every_optimal_path() {
+        // Any monotone path in a 3x3 grid is optimal: there are 6 of them.
+        let grid = Grid::new(
+            "S..
+...
+..E",
+        );
+        let nodes = astar_paths(
+            grid.start,
+            |s| *s == grid.goal,
+            |s| grid.successors(s),
+            |s| grid.manhattan(s),
+            |parent, _| parent.get_cost() + 1,
+        )
+        .unwrap();
+        let optimal: Vec<&Node<(usize, usize)>> =
+            nodes.iter().filter(|n| n.get_cost() == 4).collect();
+        assert_eq!(optimal.len(), 6);
+        assert!(optimal
+            .iter()
+            .all(|n| is_valid_path(&grid, &n.node_to_path())));
+    }
+
+    #[rstest]
+    fn test_no_path(blocked_grid: Grid) {
+        let grid = &blocked_grid;
+        assert!(dfs(grid.start, |s| *s == grid.goal, |s| grid.successors(s)).is_none());
+        assert!(bfs(grid.start, |s| *s == grid.goal, |s| grid.successors(s)).is_none());
+        assert!(astar(
+            grid.start,
+            |s| *s == grid.goal,
+            |s| grid.successors(s),
+            |s| grid.manhattan(s),
+            |parent, _| parent.get_cost() + 1,
+        )
+        .is_none());
+        assert!(astar_paths(
+            grid.start,
+            |s| *s == grid.goal,
+            |s| grid.successors(s),
+            |s| grid.manhattan(s),
+            |parent, _| parent.get_cost() + 1,
+        )
+        .is_none());
+    }
+
+    #[rstest]
+    fn test_initial_state_is_goal(open_grid: Grid) {
+        let node = bfs(open_grid.start, |_| true, |s| open_grid.successors(s)).unwrap();
+        assert_eq!(node.node_to_path(), vec![open_grid.start]);
+        assert_eq!(node.get_cost(), 0);
+    }
+
+    #[rstest]
+    fn test_bfs_stats_on_blocked_grid(blocked_grid: Grid) {
+        let grid = &blocked_grid;
+        let (node, stats) = bfs_with_stats(grid.start, |s| *s == grid.goal, |s| grid.successors(s));
+        assert!(node.is_none());
+        // Every reachable cell left of the wall is expanded exactly once.
+        assert_eq!(stats.expanded, 6);
+        assert_eq!(stats.duplicates, 0);
+        assert!(stats.generated >= stats.expanded);
+        assert!(stats.max_frontier >= 1);
+    }
+
+    #[rstest]
+    #[case(open_grid())]
+    #[case(walled_grid())]
+    fn test_heuristic_reduces_expansions(#[case] grid: Grid) {
+        let search = |heuristic: &dyn Fn(&(usize, usize)) -> usize| {
+            astar_with_stats(
+                grid.start,
+                |s| *s == grid.goal,
+                |s| grid.successors(s),
+                heuristic,
+                |parent, _| parent.get_cost() + 1,
+            )
+        };
+        let (uninformed, uninformed_stats) = search(&|_| 0);
+        let (informed, informed_stats) = search(&|s| grid.manhattan(s));
+        assert_eq!(uninformed.unwrap().get_cost(), informed.unwrap().get_cost());
+        assert!(informed_stats.expanded <= uninformed_stats.expanded);
+    }
+
+    #[rstest]
+    fn test_astar_stats_count_stale_entries() {
+        // `b` is first pushed through `a` with cost 6, then again through `c` with cost 3, so
+        // the first copy goes stale and is popped before the goal, reached for 8.
+        let successors = |s: &char| match s {
+            's' => vec!['a', 'c'],
+            'a' => vec!['b'],
+            'c' => vec!['b'],
+            'b' => vec!['g'],
+            _ => vec![],
+        };
+        let cost = |parent: &Node<char>, child: &char| {
+            parent.get_cost()
+                + match (parent.get_state(), child) {
+                    ('s', 'a') => 1,
+                    ('a', 'b') => 5,
+                    ('s', 'c') => 2,
+                    ('c', 'b') => 1,
+                    ('b', 'g') => 5,
+                    _ => 1,
+                }
+        };
+        let (node, stats) = astar_with_stats('s', |s| *s == 'g', successors, |_| 0, cost);
+        assert_eq!(node.unwrap().get_cost(), 8);
+        assert_eq!(stats.duplicates, 1);
+        let (nodes, stats) = astar_paths_with_stats('s', |s| *s == 'g', successors, |_| 0, cost);
+        let cheapest = nodes.unwrap().iter().map(|n| n.get_cost()).min().unwrap();
+        assert_eq!(cheapest, 8);
+        assert_eq!(stats.duplicates, 1);
+    }
+
+    #[rstest]
+    fn test_astar_reopens_states_with_inconsistent_heuristic() {
+        // `h(a) = 6` is admissible but not consistent with `h(x) = 0`, so `x` is first expanded
+        // through the expensive direct edge and has to be reopened once `a` finds it for 2.
+        let successors = |s: &char| match s {
+            's' => vec!['a', 'x'],
+            'a' => vec!['x'],
+            'x' => vec!['g'],
+            _ => vec![],
+        };
+        let cost = |parent: &Node<char>, child: &char| {
+            parent.get_cost()
+                + match (parent.get_state(), child) {
+                    ('s', 'x') => 3,
+                    ('x', 'g') => 5,
+                    _ => 1,
+                }
+        };
+        let heuristic = |s: &char| if *s == 'a' { 6 } else { 0 };
+        let (node, stats) = astar_with_stats('s', |s| *s == 'g', successors, heuristic, cost);
+        let node = node.unwrap();
+        assert_eq!(node.get_cost(), 7);
+        assert_eq!(node.node_to_path(), vec!['s', 'a', 'x', 'g']);
+        // `s`, `x`, `a` and `x` again.
+        assert_eq!(stats.expanded, 4);
+    }
+}
@@ -0,0 +1,439 @@
+use std::{
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet},
+    hash::Hash,
+    rc::Rc,
+    time::Instant,
+};
+
+use crate::{arena::Arena, push_expanded, Node, SearchStats};
+
+/// Every minimum-cost path from an initial state to the cheapest goals, stored as a predecessor
+/// DAG: each state on an optimal path maps to all of the states it can optimally be reached from.
+pub struct PathDag<T> {
+    initial: T,
+    goals: Vec<T>,
+    cost: usize,
+    predecessors: HashMap<T, Vec<T>>,
+}
+
+impl<T> PathDag<T>
+where
+    T: Clone + Eq + Hash,
+{
+    /// Cost of every optimal path.
+    pub fn cost(&self) -> usize {
+        self.cost
+    }
+
+    pub fn initial(&self) -> &T {
+        &self.initial
+    }
+
+    /// Goal states reached at the optimal cost.
+    pub fn goals(&self) -> &[T] {
+        &self.goals
+    }
+
+    /// States from which `state` is reached at its optimal cost. Empty for the initial state and
+    /// for states not on any optimal path.
+    pub fn predecessors(&self, state: &T) -> &[T] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// Every state lying on at least one optimal path, initial state and goals included.
+    pub fn states(&self) -> HashSet<T> {
+        self.topological_order().into_iter().collect()
+    }
+
+    /// Number of distinct optimal paths, counted without enumerating them.
+    pub fn count_paths(&self) -> usize {
+        let mut counts: HashMap<T, usize> = HashMap::new();
+        for state in self.topological_order() {
+            let count = if state == self.initial {
+                1
+            } else {
+                self.predecessors(&state).iter().map(|p| counts[p]).sum()
+            };
+            counts.insert(state, count);
+        }
+        self.goals.iter().map(|g| counts[g]).sum()
+    }
+
+    /// Every optimal path, from the initial state to a goal.
+    ///
+    /// The number of paths can grow exponentially with their length, prefer [`Self::count_paths`]
+    /// or [`Self::states`] when the paths themselves are not needed.
+    pub fn paths(&self) -> Vec<Vec<T>> {
+        let mut paths = vec![];
+        // Partial paths are built backwards, from a goal towards the initial state.
+        let mut stack: Vec<Vec<T>> = self.goals.iter().map(|g| vec![g.clone()]).collect();
+        while let Some(partial) = stack.pop() {
+            let last = partial.last().unwrap();
+            if *last == self.initial {
+                paths.push(partial.into_iter().rev().collect());
+                continue;
+            }
+            for predecessor in self.predecessors(last) {
+                let mut extended = partial.clone();
+                extended.push(predecessor.clone());
+                stack.push(extended);
+            }
+        }
+        paths
+    }
+
+    /// States on optimal paths, ordered so that every state comes after all of its predecessors.
+    fn topological_order(&self) -> Vec<T> {
+        let mut order = vec![];
+        let mut visited = HashSet::new();
+        // Iterative post-order DFS over predecessor edges; `true` marks a state whose
+        // predecessors have all been pushed already.
+        let mut stack: Vec<(T, bool)> = self.goals.iter().map(|g| (g.clone(), false)).collect();
+        while let Some((state, expanded)) = stack.pop() {
+            if expanded {
+                order.push(state);
+                continue;
+            }
+            if !visited.insert(state.clone()) {
+                continue;
+            }
+            stack.push((state.clone(), true));
+            for predecessor in self.predecessors(&state) {
+                if !visited.contains(predecessor) {
+                    stack.push((predecessor.clone(), false));
+                }
+            }
+        }
+        order
+    }
+}
+
+/// Like [`astar`](crate::astar), but records every optimal predecessor of each state instead of a
+/// single parent, returning the DAG of all minimum-cost paths to the cheapest goals.
+///
+/// The heuristic must be consistent for the DAG to be complete. The search stops once every node
+/// that could still lie on an optimal path has been expanded; goal states are never expanded.
+/// Steps may cost nothing; a free step that would close a cycle of optimal paths is left out of the
+/// DAG. As in [`astar`](crate::astar), the parent node given to `cost` can walk back its path, through
+/// the first predecessor it was reached from at its optimal cost.
+pub fn astar_dag<T, F, G, H, C>(
+    initial: T,
+    goal_test: F,
+    successors: G,
+    heuristic: H,
+    cost: C,
+) -> Option<PathDag<T>>
+where
+    T: Clone + Eq + Hash,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+    H: Fn(&T) -> usize,
+    C: Fn(&Node<T>, &T) -> usize,
+{
+    astar_dag_with_stats(initial, goal_test, successors, heuristic, cost).0
+}
+
+/// [`astar_dag`], also returning how much work the search did.
+pub fn astar_dag_with_stats<T, F, G, H, C>(
+    initial: T,
+    goal_test: F,
+    successors: G,
+    heuristic: H,
+    cost: C,
+) -> (Option<PathDag<T>>, SearchStats)
+where
+    T: Clone + Eq + Hash,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<T>,
+    H: Fn(&T) -> usize,
+    C: Fn(&Node<T>, &T) -> usize,
+{
+    let start = Instant::now();
+    let mut stats = SearchStats::default();
+    let mut frontier = BinaryHeap::new();
+    let mut best_costs = HashMap::new();
+    let mut predecessors: HashMap<T, Vec<T>> = HashMap::new();
+    let mut arena = Rc::new(Arena::new());
+    frontier.push(Node::new(initial.clone(), None, 0, heuristic(&initial)));
+    best_costs.insert(initial.clone(), 0);
+    stats.record_frontier(frontier.len());
+
+    let mut goal_cost = None;
+    let mut goals = vec![];
+
+    while let Some(current_node) = frontier.pop() {
+        if goal_cost.is_some_and(|c| current_node.cost + current_node.heuristic > c) {
+            break;
+        }
+        // A cheaper copy of this state has already been expanded.
+        if current_node.cost > best_costs[&current_node.state] {
+            stats.duplicates += 1;
+            continue;
+        }
+        if goal_test(&current_node.state) {
+            goal_cost = Some(current_node.cost);
+            goals.push(current_node.state);
+            continue;
+        }
+        stats.expanded += 1;
+        let index = arena.next_index();
+        let current_node = current_node.with_arena(Rc::clone(&arena));
+        for child in successors(&current_node.state) {
+            stats.generated += 1;
+            let new_cost = cost(&current_node, &child);
+            match best_costs.entry(child.clone()) {
+                Entry::Occupied(e) if new_cost > *e.get() => continue,
+                Entry::Occupied(e) if new_cost == *e.get() => {
+                    // A free step back to a state the current one was reached from would close
+                    // a cycle of optimal paths.
+                    if new_cost > current_node.cost
+                        || !leads_to(&predecessors, &child, &current_node.state)
+                    {
+                        predecessors
+                            .entry(child)
+                            .or_default()
+                            .push(current_node.state.clone());
+                    }
+                    continue;
+                }
+                Entry::Occupied(mut e) => {
+                    e.insert(new_cost);
+                }
+                Entry::Vacant(e) => {
+                    e.insert(new_cost);
+                }
+            }
+            predecessors.insert(child.clone(), vec![current_node.state.clone()]);
+            let h = heuristic(&child);
+            frontier.push(Node::new(child, Some(index), new_cost, h));
+        }
+        push_expanded(&mut arena, current_node);
+        stats.record_frontier(frontier.len());
+    }
+
+    let dag = goal_cost.map(|cost| {
+        let mut dag = PathDag {
+            initial,
+            goals,
+            cost,
+            predecessors,
+        };
+        // The search also recorded predecessors for states that turned out not to lead to a goal
+        // optimally.
+        let states = dag.states();
+        dag.predecessors.retain(|state, _| states.contains(state));
+        dag
+    });
+    (dag, stats.finish(start))
+}
+
+/// Whether `to` is `from` or is reached from it along the recorded predecessor edges.
+fn leads_to<T: Eq + Hash>(predecessors: &HashMap<T, Vec<T>>, from: &T, to: &T) -> bool {
+    let mut stack = vec![to];
+    let mut visited = HashSet::new();
+    while let Some(state) = stack.pop() {
+        if state == from {
+            return true;
+        }
+        if visited.insert(state) {
+            stack.extend(predecessors.get(state).into_iter().flatten());
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    type Cell = (usize, usize);
+
+    /// Moving right or down on an open `size`x`size` grid, one unit per step.
+    fn monotone_successors(size: usize) -> impl Fn(&Cell) -> Vec<Cell> {
+        move |&(x, y)| {
+            let mut successors = vec![];
+            if x + 1 < size {
+                successors.push((x + 1, y));
+            }
+            if y + 1 < size {
+                successors.push((x, y + 1));
+            }
+            successors
+        }
+    }
+
+    fn unit_cost(parent: &Node<Cell>, _: &Cell) -> usize {
+        parent.get_cost() + 1
+    }
+
+    fn manhattan_to((gx, gy): Cell) -> impl Fn(&Cell) -> usize {
+        move |&(x, y)| gx.abs_diff(x) + gy.abs_diff(y)
+    }
+
+    #[rstest]
+    #[case(2, 2)]
+    #[case(3, 6)]
+    #[case(4, 20)]
+    fn test_count_paths_on_open_grid(#[case] size: usize, #[case] expected: usize) {
+        let goal = (size - 1, size - 1);
+        let dag = astar_dag(
+            (0, 0),
+            |s| *s == goal,
+            monotone_successors(size),
+            manhattan_to(goal),
+            unit_cost,
+        )
+        .unwrap();
+        assert_eq!(dag.cost(), 2 * (size - 1));
+        assert_eq!(dag.count_paths(), expected);
+        assert_eq!(dag.paths().len(), expected);
+        assert_eq!(dag.states().len(), size * size);
+    }
+
+    #[rstest]
+    fn test_dag_stats_on_open_grid() {
+        let (dag, stats) = astar_dag_with_stats(
+            (0, 0),
+            |s| *s == (2, 2),
+            monotone_successors(3),
+            manhattan_to((2, 2)),
+            unit_cost,
+        );
+        assert_eq!(dag.unwrap().count_paths(), 6);
+        // Every cell but the goal is expanded once, reached again at equal cost instead of pushed.
+        assert_eq!(stats.expanded, 8);
+        assert_eq!(stats.generated, 12);
+        assert_eq!(stats.duplicates, 0);
+    }
+
+    #[rstest]
+    fn test_paths_are_distinct_and_optimal() {
+        let dag = astar_dag(
+            (0, 0),
+            |s| *s == (2, 2),
+            monotone_successors(3),
+            manhattan_to((2, 2)),
+            unit_cost,
+        )
+        .unwrap();
+        let paths: HashSet<Vec<Cell>> = dag.paths().into_iter().collect();
+        assert_eq!(paths.len(), 6);
+        for path in &paths {
+            assert_eq!(path.first(), Some(&(0, 0)));
+            assert_eq!(path.last(), Some(&(2, 2)));
+            assert_eq!(path.len(), 5);
+        }
+        let mut predecessors = dag.predecessors(&(1, 1)).to_vec();
+        predecessors.sort();
+        assert_eq!(predecessors, vec![(0, 1), (1, 0)]);
+        assert!(dag.predecessors(&(0, 0)).is_empty());
+    }
+
+    #[rstest]
+    fn test_suboptimal_branches_are_left_out() {
+        // Two routes from 'a' to 'd': through 'b' (cost 2) and through 'c' (cost 3).
+        let successors = |s: &char| match s {
+            'a' => vec!['b', 'c'],
+            'b' | 'c' => vec!['d'],
+            _ => vec![],
+        };
+        let cost = |parent: &Node<char>, child: &char| {
+            parent.get_cost()
+                + if *parent.get_state() == 'c' && *child == 'd' {
+                    2
+                } else {
+                    1
+                }
+        };
+        let dag = astar_dag('a', |s| *s == 'd', successors, |_| 0, cost).unwrap();
+        assert_eq!(dag.cost(), 2);
+        assert_eq!(dag.count_paths(), 1);
+        assert_eq!(dag.paths(), vec![vec!['a', 'b', 'd']]);
+        assert_eq!(dag.states(), HashSet::from(['a', 'b', 'd']));
+        assert_eq!(dag.predecessors(&'d'), ['b']);
+        assert!(dag.predecessors(&'c').is_empty());
+    }
+
+    #[rstest]
+    fn test_cost_sees_the_parent_path() {
+        // Each step costs the length of the path so far, which is only right if `cost` can read it.
+        let cost = |parent: &Node<Cell>, child: &Cell| {
+            let path = parent.node_to_path();
+            assert_eq!(path.first(), Some(&(0, 0)));
+            assert_eq!(path.last(), Some(parent.get_state()));
+            assert_eq!(path.len(), parent.depth() + 1);
+            assert!(monotone_successors(3)(parent.get_state()).contains(child));
+            parent.get_cost() + path.len()
+        };
+        let dag = astar_dag(
+            (0, 0),
+            |s| *s == (2, 2),
+            monotone_successors(3),
+            |_| 0,
+            cost,
+        )
+        .unwrap();
+        assert_eq!(dag.cost(), (1..=4).sum());
+        assert_eq!(dag.count_paths(), 6);
+    }
+
+    #[rstest]
+    fn test_free_steps_do_not_close_cycles() {
+        // 'a' and 'b' lead to each other for free, and 'a' also reaches 'b' through 'c'.
+        let successors = |s: &char| match s {
+            'a' => vec!['b', 'c'],
+            'b' => vec!['a', 'd'],
+            'c' => vec!['b'],
+            _ => vec![],
+        };
+        let cost =
+            |parent: &Node<char>, child: &char| parent.get_cost() + usize::from(*child == 'd');
+        let dag = astar_dag('a', |s| *s == 'd', successors, |_| 0, cost).unwrap();
+        assert_eq!(dag.cost(), 1);
+        assert!(dag.predecessors(&'a').is_empty());
+        let mut paths = dag.paths();
+        paths.sort();
+        assert_eq!(paths, vec![vec!['a', 'b', 'd'], vec!['a', 'c', 'b', 'd']]);
+        assert_eq!(dag.count_paths(), 2);
+    }
+
+    #[rstest]
+    fn test_every_cheapest_goal_is_kept() {
+        // Both corners next to the start are goals at the same cost, the far corner is not.
+        let goals = [(1, 0), (0, 1), (1, 1)];
+        let dag = astar_dag(
+            (0, 0),
+            |s| goals.contains(s),
+            monotone_successors(2),
+            |_| 0,
+            unit_cost,
+        )
+        .unwrap();
+        let mut found = dag.goals().to_vec();
+        found.sort();
+        assert_eq!(found, vec![(0, 1), (1, 0)]);
+        assert_eq!(dag.count_paths(), 2);
+        assert_eq!(*dag.initial(), (0, 0));
+    }
+
+    #[rstest]
+    fn test_initial_state_is_goal() {
+        let dag = astar_dag((0, 0), |_| true, monotone_successors(2), |_| 0, unit_cost).unwrap();
+        assert_eq!(dag.cost(), 0);
+        assert_eq!(dag.paths(), vec![vec![(0, 0)]]);
+        assert_eq!(dag.count_paths(), 1);
+    }
+
+    #[rstest]
+    fn test_no_path() {
+        let dag = astar_dag(
+            (1, 1),
+            |s| *s == (0, 0),
+            monotone_successors(2),
+            |_| 0,
+            unit_cost,
+        );
+        assert!(dag.is_none());
+    }
+}
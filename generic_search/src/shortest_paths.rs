@@ -0,0 +1,231 @@
+use std::{
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    rc::Rc,
+    time::Instant,
+};
+
+use crate::{arena::Arena, Node, SearchStats};
+
+/// Single-source Dijkstra: the cost of the cheapest path from `initial` to every reachable state.
+///
+/// `successors` returns each neighbor together with the (non-negative) cost of the step.
+pub fn dijkstra<T, G>(initial: T, successors: G) -> HashMap<T, usize>
+where
+    T: Clone + Eq + Hash,
+    G: Fn(&T) -> Vec<(T, usize)>,
+{
+    dijkstra_with_stats(initial, successors).0
+}
+
+/// [`dijkstra`], also returning how much work the search did.
+pub fn dijkstra_with_stats<T, G>(initial: T, successors: G) -> (HashMap<T, usize>, SearchStats)
+where
+    T: Clone + Eq + Hash,
+    G: Fn(&T) -> Vec<(T, usize)>,
+{
+    let start = Instant::now();
+    let mut stats = SearchStats::default();
+    let mut frontier = BinaryHeap::new();
+    let mut distances = HashMap::new();
+    frontier.push(Node::new(initial.clone(), None, 0, 0));
+    distances.insert(initial, 0);
+    stats.record_frontier(frontier.len());
+
+    while let Some(current_node) = frontier.pop() {
+        // A cheaper copy of this state has already been expanded.
+        if current_node.cost > distances[&current_node.state] {
+            stats.duplicates += 1;
+            continue;
+        }
+        stats.expanded += 1;
+        for (child, step_cost) in successors(&current_node.state) {
+            stats.generated += 1;
+            let new_cost = current_node.cost + step_cost;
+            match distances.entry(child.clone()) {
+                Entry::Vacant(e) => {
+                    e.insert(new_cost);
+                    frontier.push(Node::new(child, None, new_cost, 0));
+                }
+                Entry::Occupied(mut e) if new_cost < *e.get() => {
+                    e.insert(new_cost);
+                    frontier.push(Node::new(child, None, new_cost, 0));
+                }
+                _ => {}
+            }
+        }
+        stats.record_frontier(frontier.len());
+    }
+    (distances, stats.finish(start))
+}
+
+/// Uniform-cost search: Dijkstra that stops as soon as a goal state is popped.
+///
+/// Equivalent to [`astar`](crate::astar) with a zero heuristic, but with step costs returned
+/// directly by `successors` instead of a `cost` closure.
+pub fn dijkstra_to_goal<T, F, G>(initial: T, goal_test: F, successors: G) -> Option<Node<T>>
+where
+    T: Clone + Eq + Hash,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<(T, usize)>,
+{
+    dijkstra_to_goal_with_stats(initial, goal_test, successors).0
+}
+
+/// [`dijkstra_to_goal`], also returning how much work the search did.
+pub fn dijkstra_to_goal_with_stats<T, F, G>(
+    initial: T,
+    goal_test: F,
+    successors: G,
+) -> (Option<Node<T>>, SearchStats)
+where
+    T: Clone + Eq + Hash,
+    F: Fn(&T) -> bool,
+    G: Fn(&T) -> Vec<(T, usize)>,
+{
+    let start = Instant::now();
+    let mut stats = SearchStats::default();
+    let mut frontier = BinaryHeap::new();
+    let mut distances = HashMap::new();
+    let mut arena = Arena::new();
+    frontier.push(Node::new(initial.clone(), None, 0, 0));
+    distances.insert(initial, 0);
+    stats.record_frontier(frontier.len());
+
+    while let Some(current_node) = frontier.pop() {
+        if current_node.cost > distances[&current_node.state] {
+            stats.duplicates += 1;
+            continue;
+        }
+        if goal_test(&current_node.state) {
+            let node = current_node.with_arena(Rc::new(arena));
+            return (Some(node), stats.finish(start));
+        }
+        stats.expanded += 1;
+        let children = successors(&current_node.state);
+        let index = arena.push(current_node.state, current_node.parent);
+        for (child, step_cost) in children {
+            stats.generated += 1;
+            let new_cost = current_node.cost + step_cost;
+            match distances.entry(child.clone()) {
+                Entry::Vacant(e) => {
+                    e.insert(new_cost);
+                    frontier.push(Node::new(child, Some(index), new_cost, 0));
+                }
+                Entry::Occupied(mut e) if new_cost < *e.get() => {
+                    e.insert(new_cost);
+                    frontier.push(Node::new(child, Some(index), new_cost, 0));
+                }
+                _ => {}
+            }
+        }
+        stats.record_frontier(frontier.len());
+    }
+    (None, stats.finish(start))
+}
+
+/// Number of steps from `initial` to every reachable state of an unweighted graph.
+pub fn bfs_distances<T, G>(initial: T, successors: G) -> HashMap<T, usize>
+where
+    T: Clone + Eq + Hash,
+    G: Fn(&T) -> Vec<T>,
+{
+    let mut frontier = VecDeque::new();
+    let mut distances = HashMap::new();
+    frontier.push_back((initial.clone(), 0));
+    distances.insert(initial, 0);
+
+    while let Some((current, distance)) = frontier.pop_front() {
+        for child in successors(&current) {
+            if let Entry::Vacant(e) = distances.entry(child.clone()) {
+                e.insert(distance + 1);
+                frontier.push_back((child, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::astar;
+
+    /// Weighted directed graph:
+    ///
+    /// ```text
+    /// a --1--> b --1--> c --1--> d
+    ///  \                        ^
+    ///   `---------5------------'
+    /// e (unreachable)
+    /// ```
+    fn successors(state: &char) -> Vec<(char, usize)> {
+        match state {
+            'a' => vec![('b', 1), ('d', 5)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 1)],
+            'e' => vec![('a', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra_distances() {
+        let distances = dijkstra('a', successors);
+        assert_eq!(
+            distances,
+            HashMap::from([('a', 0), ('b', 1), ('c', 2), ('d', 3)])
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_to_goal() {
+        let node = dijkstra_to_goal('a', |s| *s == 'd', successors).unwrap();
+        assert_eq!(node.get_cost(), 3);
+        assert_eq!(node.node_to_path(), vec!['a', 'b', 'c', 'd']);
+        assert!(dijkstra_to_goal('a', |s| *s == 'e', successors).is_none());
+    }
+
+    #[test]
+    fn test_dijkstra_stats() {
+        // `d` is first pushed through the direct edge with cost 5, then again through `c` with
+        // cost 3, so the first copy goes stale.
+        let (_, stats) = dijkstra_with_stats('a', successors);
+        assert_eq!(stats.expanded, 4);
+        assert_eq!(stats.generated, 4);
+        assert_eq!(stats.duplicates, 1);
+        let (node, stats) = dijkstra_to_goal_with_stats('a', |s| *s == 'd', successors);
+        assert_eq!(node.unwrap().get_cost(), 3);
+        assert_eq!(stats.expanded, 3);
+        assert_eq!(stats.duplicates, 0);
+    }
+
+    #[test]
+    fn test_dijkstra_to_goal_matches_astar() {
+        let node = astar(
+            'a',
+            |s| *s == 'd',
+            |s| successors(s).into_iter().map(|(c, _)| c).collect(),
+            |_| 0,
+            |parent, child| {
+                let (_, step) = successors(parent.get_state())
+                    .into_iter()
+                    .find(|(c, _)| c == child)
+                    .unwrap();
+                parent.get_cost() + step
+            },
+        )
+        .unwrap();
+        let uniform = dijkstra_to_goal('a', |s| *s == 'd', successors).unwrap();
+        assert_eq!(node.get_cost(), uniform.get_cost());
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let distances = bfs_distances('a', |s| successors(s).into_iter().map(|(c, _)| c).collect());
+        assert_eq!(
+            distances,
+            HashMap::from([('a', 0), ('b', 1), ('c', 2), ('d', 1)])
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+/// Counters collected by the `*_with_stats` variants of the searches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    /// Nodes popped from the frontier and passed to `successors`.
+    pub expanded: usize,
+    /// States returned by `successors`, whether or not they were pushed.
+    pub generated: usize,
+    /// Stale nodes popped after a cheaper copy of their state had been found, and skipped. Always
+    /// 0 for `dfs`, `bfs` and `bidirectional_bfs`, which never push a state twice.
+    pub duplicates: usize,
+    /// Largest number of nodes waiting in the frontier at once.
+    pub max_frontier: usize,
+    /// Wall-clock time of the whole search.
+    pub elapsed: Duration,
+}
+
+impl SearchStats {
+    pub(crate) fn record_frontier(&mut self, len: usize) {
+        self.max_frontier = self.max_frontier.max(len);
+    }
+
+    pub(crate) fn finish(mut self, start: Instant) -> Self {
+        self.elapsed = start.elapsed();
+        self
+    }
+}
+
+impl Display for SearchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expanded {}, generated {}, duplicates {}, max frontier {}, in {:?}",
+            self.expanded, self.generated, self.duplicates, self.max_frontier, self.elapsed
+        )
+    }
+}
@@ -0,0 +1,287 @@
+//! Points, vectors and compass directions on a grid whose `y` axis points down.
+
+use std::{
+    fmt::Display,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// A position in a grid: `x` is the column and `y` the row.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+/// A displacement between two points.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Vector {
+    pub dx: isize,
+    pub dy: isize,
+}
+
+/// One of the four cardinal directions, north being up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// `self + vector`, or `None` if it would go left of or above the origin.
+    pub fn checked_add(self, vector: Vector) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(vector.dx)?,
+            y: self.y.checked_add_signed(vector.dy)?,
+        })
+    }
+
+    /// `self + vector`, or `None` if it falls outside a `width`x`height` grid.
+    pub fn checked_add_within(self, vector: Vector, width: usize, height: usize) -> Option<Self> {
+        self.checked_add(vector)
+            .filter(|point| point.x < width && point.y < height)
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Point> for (usize, usize) {
+    fn from(point: Point) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Self) -> Vector {
+        Vector::new(
+            self.x as isize - other.x as isize,
+            self.y as isize - other.y as isize,
+        )
+    }
+}
+
+impl Vector {
+    pub const NORTH: Self = Self::new(0, -1);
+    pub const NORTH_EAST: Self = Self::new(1, -1);
+    pub const EAST: Self = Self::new(1, 0);
+    pub const SOUTH_EAST: Self = Self::new(1, 1);
+    pub const SOUTH: Self = Self::new(0, 1);
+    pub const SOUTH_WEST: Self = Self::new(-1, 1);
+    pub const WEST: Self = Self::new(-1, 0);
+    pub const NORTH_WEST: Self = Self::new(-1, -1);
+
+    /// Unit steps to the four orthogonal neighbors, clockwise from north.
+    pub const CARDINALS: [Self; 4] = [Self::NORTH, Self::EAST, Self::SOUTH, Self::WEST];
+
+    /// Unit steps to the four diagonal neighbors, clockwise from north-east.
+    pub const DIAGONALS: [Self; 4] = [
+        Self::NORTH_EAST,
+        Self::SOUTH_EAST,
+        Self::SOUTH_WEST,
+        Self::NORTH_WEST,
+    ];
+
+    /// Unit steps to all eight neighbors, clockwise from north.
+    pub const ALL: [Self; 8] = [
+        Self::NORTH,
+        Self::NORTH_EAST,
+        Self::EAST,
+        Self::SOUTH_EAST,
+        Self::SOUTH,
+        Self::SOUTH_WEST,
+        Self::WEST,
+        Self::NORTH_WEST,
+    ];
+
+    pub const fn new(dx: isize, dy: isize) -> Self {
+        Self { dx, dy }
+    }
+}
+
+impl From<(isize, isize)> for Vector {
+    fn from((dx, dy): (isize, isize)) -> Self {
+        Self { dx, dy }
+    }
+}
+
+impl Add for Vector {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.dx + other.dx, self.dy + other.dy)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.dx - other.dx, self.dy - other.dy)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.dx, -self.dy)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Self;
+
+    fn mul(self, factor: isize) -> Self {
+        Self::new(self.dx * factor, self.dy * factor)
+    }
+}
+
+impl Direction {
+    /// Clockwise from north.
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// Reads the arrows `^`, `>`, `v` and `<` used by the puzzles.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Self::North),
+            '>' => Some(Self::East),
+            'v' => Some(Self::South),
+            '<' => Some(Self::West),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Self::North => '^',
+            Self::East => '>',
+            Self::South => 'v',
+            Self::West => '<',
+        }
+    }
+
+    pub fn vector(self) -> Vector {
+        Vector::CARDINALS[self as usize]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::East | Self::West)
+    }
+
+    /// Fewest 90 degree turns, in either direction, to face `other`: 0, 1 or 2.
+    pub fn quarter_turns_to(self, other: Self) -> usize {
+        let clockwise = (other as usize + 4 - self as usize) % 4;
+        clockwise.min(4 - clockwise)
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(direction: Direction) -> Self {
+        direction.vector()
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(Direction::North, Direction::East, Direction::West)]
+    #[case(Direction::East, Direction::South, Direction::North)]
+    #[case(Direction::West, Direction::North, Direction::South)]
+    fn test_turns(#[case] direction: Direction, #[case] right: Direction, #[case] left: Direction) {
+        assert_eq!(direction.turn_right(), right);
+        assert_eq!(direction.turn_left(), left);
+        assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+        assert_eq!(direction.vector(), -direction.reverse().vector());
+    }
+
+    #[rstest]
+    #[case(Direction::North, Direction::North, 0)]
+    #[case(Direction::North, Direction::West, 1)]
+    #[case(Direction::South, Direction::East, 1)]
+    #[case(Direction::East, Direction::West, 2)]
+    fn test_quarter_turns(#[case] from: Direction, #[case] to: Direction, #[case] expected: usize) {
+        assert_eq!(from.quarter_turns_to(to), expected);
+        assert_eq!(to.quarter_turns_to(from), expected);
+    }
+
+    #[rstest]
+    fn test_arrows_round_trip() {
+        for direction in Direction::ALL {
+            assert_eq!(Direction::from_arrow(direction.arrow()), Some(direction));
+        }
+        assert_eq!(Direction::from_arrow('.'), None);
+    }
+
+    #[rstest]
+    fn test_point_arithmetic() {
+        let (a, b) = (Point::new(8, 1), Point::new(5, 2));
+        let delta = b - a;
+        assert_eq!(delta, Vector::new(-3, 1));
+        assert_eq!(a.checked_add(delta), Some(b));
+        assert_eq!(a.checked_add(delta * 3), None);
+        assert_eq!(b.checked_add(-delta * 2), Some(Point::new(11, 0)));
+        assert_eq!(
+            b.checked_add_within(-delta * 2, 12, 12),
+            Some(Point::new(11, 0))
+        );
+        assert_eq!(b.checked_add_within(-delta * 2, 11, 12), None);
+        assert_eq!(a.manhattan_distance(b), 4);
+    }
+
+    #[rstest]
+    fn test_neighbor_vectors() {
+        assert_eq!(
+            Vector::ALL.iter().step_by(2).copied().collect::<Vec<_>>(),
+            Vector::CARDINALS
+        );
+        assert_eq!(
+            Vector::ALL
+                .iter()
+                .skip(1)
+                .step_by(2)
+                .copied()
+                .collect::<Vec<_>>(),
+            Vector::DIAGONALS
+        );
+        assert_eq!(
+            Vector::ALL.into_iter().fold(Vector::default(), Add::add),
+            Vector::default()
+        );
+    }
+}
@@ -0,0 +1,415 @@
+//! Rectangular grids stored in a single row-major `Vec`.
+//!
+//! Positions are `(x, y)` pairs, `x` being the column and `y` the row, with `(0, 0)` in the top
+//! left corner as in the puzzle inputs. Grids are usually parsed from their character map, one
+//! line per row, with each cell type implementing [`FromChar`]. The [`geometry`] module provides
+//! typed points, vectors and directions to move around them.
+
+pub mod geometry;
+
+pub use geometry::{Direction, Point, Vector};
+
+use anyhow::Result;
+use aoc_common::{PuzzleError, PuzzleResult};
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// A `(x, y)` position in a grid.
+pub type Position = (usize, usize);
+
+/// A cell that can be read from a single character of the puzzle input.
+pub trait FromChar: Sized {
+    fn from_char(c: char) -> Result<Self>;
+}
+
+impl FromChar for char {
+    fn from_char(c: char) -> Result<Self> {
+        Ok(c)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `width`x`height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> PuzzleResult<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(PuzzleError::dimension_mismatch(
+                format!("Length of row {}", y + 1),
+                width,
+                row.len(),
+            ));
+        }
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    /// Parses a character map, building each cell from its position and character.
+    ///
+    /// Useful when some characters mark positions of interest (a start, a robot, ...) that the
+    /// caller wants to record while parsing. An error from `cell` is reported at the position of
+    /// its character.
+    pub fn parse_with<F>(s: &str, mut cell: F) -> PuzzleResult<Self>
+    where
+        F: FnMut(Position, char) -> Result<T>,
+    {
+        let rows = s
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.char_indices()
+                    .enumerate()
+                    .map(|(x, (offset, c))| {
+                        cell((x, y), c).map_err(|error| {
+                            PuzzleError::malformed(y, offset, line, format!("{:#}", error))
+                        })
+                    })
+                    .collect::<PuzzleResult<Vec<T>>>()
+            })
+            .collect::<PuzzleResult<Vec<Vec<T>>>>()?;
+        let grid = Self::from_rows(rows)?;
+        if grid.width == 0 || grid.height == 0 {
+            return Err(PuzzleError::malformed(
+                0,
+                0,
+                s.lines().next().unwrap_or_default(),
+                "Grid must have at least one row and one column",
+            ));
+        }
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.offset(position)])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            let offset = self.offset(position);
+            Some(&mut self.cells[offset])
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `position` must be inside the grid.
+    pub unsafe fn get_unchecked(&self, position: Position) -> &T {
+        self.cells.get_unchecked(self.offset(position))
+    }
+
+    /// # Safety
+    ///
+    /// `position` must be inside the grid.
+    pub unsafe fn get_unchecked_mut(&mut self, position: Position) -> &mut T {
+        let offset = self.offset(position);
+        self.cells.get_unchecked_mut(offset)
+    }
+
+    /// `point + vector`, if it is inside the grid.
+    pub fn translate(&self, point: Point, vector: Vector) -> Option<Point> {
+        point.checked_add_within(vector, self.width, self.height)
+    }
+
+    /// Position `steps` times `vector` away from `position`, if it is inside the grid.
+    pub fn step(&self, position: Position, vector: Vector, steps: usize) -> Option<Position> {
+        let scaled = Vector::new(
+            vector.dx.checked_mul(steps as isize)?,
+            vector.dy.checked_mul(steps as isize)?,
+        );
+        self.translate(position.into(), scaled).map(Into::into)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell, row by row, matching `predicate`.
+    pub fn find<P>(&self, mut predicate: P) -> Option<Position>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(p, _)| p)
+    }
+
+    /// The up to four orthogonal neighbors of `position`: up, right, down and left.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Vector::CARDINALS
+            .into_iter()
+            .filter_map(move |offset| self.step(position, offset, 1))
+    }
+
+    /// The up to eight neighbors of `position`, diagonals included, clockwise from the top.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Vector::ALL
+            .into_iter()
+            .filter_map(move |offset| self.step(position, offset, 1))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Panics if `x` is not within the grid, like [`Self::row`].
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Cells from `start` (included) in the direction of `direction` until the edge of the grid.
+    pub fn ray(&self, start: Position, direction: Vector) -> impl Iterator<Item = &T> {
+        (0..)
+            .map_while(move |steps| self.step(start, direction, steps))
+            .map(|position| &self[position])
+    }
+
+    /// Cells from `start` going down and to the right.
+    pub fn diagonal(&self, start: Position) -> impl Iterator<Item = &T> {
+        self.ray(start, Vector::SOUTH_EAST)
+    }
+
+    /// Cells from `start` going down and to the left.
+    pub fn anti_diagonal(&self, start: Position) -> impl Iterator<Item = &T> {
+        self.ray(start, Vector::SOUTH_WEST)
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn offset(&self, (x, y): Position) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T: FromChar> FromStr for Grid<T> {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> PuzzleResult<Self> {
+        Self::parse_with(s, |_, c| T::from_char(c))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside of the {}x{} grid",
+                position, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the {}x{} grid", position, width, height))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use rstest::*;
+
+    #[fixture]
+    fn grid() -> Grid<char> {
+        "abc\ndef\nghi\njkl".parse().unwrap()
+    }
+
+    #[rstest]
+    fn test_parse(grid: Grid<char>) {
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 4)), None);
+        assert_eq!(unsafe { *grid.get_unchecked((1, 3)) }, 'k');
+        assert_eq!(grid.find(|&c| c == 'h'), Some((1, 2)));
+    }
+
+    #[rstest]
+    #[case("ab\nc")]
+    #[case("")]
+    fn test_parse_invalid(#[case] input: &str) {
+        assert!(input.parse::<Grid<char>>().is_err());
+    }
+
+    #[rstest]
+    fn test_parse_with_cell_errors() {
+        let grid = Grid::parse_with("12\n3x", |_, c| {
+            c.to_digit(10).ok_or_else(|| anyhow!("Not a digit: {}", c))
+        });
+        assert_eq!(
+            grid.unwrap_err().to_string(),
+            "Line 2, column 2: Not a digit: x\n2 | 3x\n  |  ^"
+        );
+        let ragged = "ab\nc".parse::<Grid<char>>();
+        assert_eq!(
+            ragged.unwrap_err().to_string(),
+            "Length of row 2 should be 2, found 1"
+        );
+    }
+
+    #[rstest]
+    #[case((0, 0), vec![(1, 0), (0, 1)])]
+    #[case((1, 1), vec![(1, 0), (2, 1), (1, 2), (0, 1)])]
+    #[case((2, 3), vec![(2, 2), (1, 3)])]
+    fn test_neighbors4(
+        grid: Grid<char>,
+        #[case] position: Position,
+        #[case] expected: Vec<Position>,
+    ) {
+        assert_eq!(grid.neighbors4(position).collect::<Vec<_>>(), expected);
+    }
+
+    #[rstest]
+    #[case((0, 0), 3)]
+    #[case((1, 1), 8)]
+    #[case((1, 3), 5)]
+    fn test_neighbors8(grid: Grid<char>, #[case] position: Position, #[case] expected: usize) {
+        assert_eq!(grid.neighbors8(position).count(), expected);
+    }
+
+    #[rstest]
+    fn test_views(grid: Grid<char>) {
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 4);
+        assert_eq!(grid.column(2).collect::<String>(), "cfil");
+        assert_eq!(grid.diagonal((0, 1)).collect::<String>(), "dhl");
+        assert_eq!(grid.anti_diagonal((2, 0)).collect::<String>(), "ceg");
+        assert_eq!(
+            grid.ray((2, 3), Vector::NORTH_WEST).collect::<String>(),
+            "lhd"
+        );
+    }
+
+    #[rstest]
+    fn test_translate(grid: Grid<char>) {
+        let point = Point::new(1, 3);
+        assert_eq!(
+            grid.translate(point, Direction::North.vector()),
+            Some(Point::new(1, 2))
+        );
+        assert_eq!(grid.translate(point, Direction::South.vector()), None);
+        assert_eq!(grid[point], 'k');
+    }
+
+    #[rstest]
+    fn test_display_round_trips(grid: Grid<char>) {
+        assert_eq!(grid.to_string(), "abc\ndef\nghi\njkl\n");
+    }
+
+    #[rstest]
+    fn test_index_mut_and_map() {
+        let mut grid = Grid::new(2, 2, 0);
+        grid[(1, 0)] = 5;
+        *grid.get_mut((0, 1)).unwrap() = 3;
+        assert!(grid.get_mut((2, 0)).is_none());
+        assert_eq!(
+            grid.map(|v| v * 2),
+            Grid::from_rows(vec![vec![0, 10], vec![6, 0]]).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn test_index_out_of_bounds(grid: Grid<char>) {
+        let _ = grid[(3, 0)];
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Column 3 is out of bounds")]
+    fn test_column_out_of_bounds(grid: Grid<char>) {
+        let _ = grid.column(3);
+    }
+}